@@ -47,6 +47,17 @@ abstract class AbstractProxy implements AutoCloseable, NativeProxy {
 
   abstract void doClose(long pointer) throws Exception;
 
+  /**
+   * Throw the error returned by a native method, if there was one
+   *
+   * @param error the error, or null if the method succeeded
+   */
+  static void checkError(DataFusionException error) {
+    if (error != null) {
+      throw error;
+    }
+  }
+
   // Ensure native library is loaded before any proxy object is used
   static {
     JNILoader.load();
@@ -0,0 +1,60 @@
+package org.apache.arrow.datafusion;
+
+import java.util.List;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.vector.FieldVector;
+
+/**
+ * Accumulates values for one group of an aggregate user defined function, which can be registered
+ * with {@link SessionContext#registerUdaf}. A new accumulator is created for each group, and
+ * accumulators may be called from native threads while queries execute. Each accumulator holds a
+ * JNI global reference until it is closed, so aggregating into a very large number of groups can
+ * exhaust the JVM's global reference table.
+ */
+public interface Accumulator extends AutoCloseable {
+  /**
+   * Update the accumulator with a batch of input values
+   *
+   * @param values the input values, with one vector per function argument. The vectors are closed
+   *     once this method returns.
+   * @throws Exception if updating fails
+   */
+  void updateBatch(List<FieldVector> values) throws Exception;
+
+  /**
+   * Merge intermediate states produced by other accumulators into this accumulator
+   *
+   * @param states the states to merge, with one vector per state field and one row per
+   *     accumulator. The vectors are closed once this method returns.
+   * @throws Exception if merging fails
+   */
+  void mergeBatch(List<FieldVector> states) throws Exception;
+
+  /**
+   * Get the intermediate state of the accumulator
+   *
+   * @param allocator the allocator to use for the state vectors
+   * @return one vector per state field, each containing a single value. The vectors are closed by
+   *     the caller.
+   * @throws Exception if getting the state fails
+   */
+  List<FieldVector> state(BufferAllocator allocator) throws Exception;
+
+  /**
+   * Get the final result of the aggregate
+   *
+   * @param allocator the allocator to use for the result vector
+   * @return a vector containing the single result value, which is closed by the caller
+   * @throws Exception if evaluating fails
+   */
+  FieldVector evaluate(BufferAllocator allocator) throws Exception;
+
+  /**
+   * Release any resources held by the accumulator once it is no longer used. By default this does
+   * nothing.
+   *
+   * @throws Exception if closing fails
+   */
+  @Override
+  default void close() throws Exception {}
+}
@@ -0,0 +1,93 @@
+package org.apache.arrow.datafusion;
+
+import java.util.List;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.util.AutoCloseables;
+import org.apache.arrow.vector.FieldVector;
+
+/** Methods called from the native library to use an {@link Accumulator} */
+final class AccumulatorCallbacks {
+  private final BufferAllocator allocator;
+  private final Accumulator accumulator;
+
+  AccumulatorCallbacks(BufferAllocator allocator, Accumulator accumulator) {
+    this.allocator = allocator;
+    this.accumulator = accumulator;
+  }
+
+  /**
+   * Update the accumulator with input arrays exported by the native library
+   *
+   * @param arrays addresses of the exported arrays, which are owned by this method
+   * @param schemas addresses of the exported schemas
+   * @throws Exception if updating fails
+   */
+  void updateBatch(long[] arrays, long[] schemas) throws Exception {
+    List<FieldVector> values = ArrowVectors.importVectors(allocator, arrays, schemas);
+    try {
+      accumulator.updateBatch(values);
+    } finally {
+      AutoCloseables.close(values);
+    }
+  }
+
+  /**
+   * Merge state arrays exported by the native library into the accumulator
+   *
+   * @param arrays addresses of the exported arrays, which are owned by this method
+   * @param schemas addresses of the exported schemas
+   * @throws Exception if merging fails
+   */
+  void mergeBatch(long[] arrays, long[] schemas) throws Exception {
+    List<FieldVector> states = ArrowVectors.importVectors(allocator, arrays, schemas);
+    try {
+      accumulator.mergeBatch(states);
+    } finally {
+      AutoCloseables.close(states);
+    }
+  }
+
+  /**
+   * Export the accumulator state into structs allocated by the native library
+   *
+   * @param arrays addresses of the array structs to export into
+   * @param schemas addresses of the schema structs to export into
+   * @throws Exception if getting the state fails
+   */
+  void state(long[] arrays, long[] schemas) throws Exception {
+    List<FieldVector> states = accumulator.state(allocator);
+    try {
+      if (states.size() != arrays.length) {
+        throw new IllegalStateException(
+            "Expected " + arrays.length + " state vectors but got " + states.size());
+      }
+      for (int i = 0; i < arrays.length; ++i) {
+        ArrowVectors.exportVector(allocator, states.get(i), arrays[i], schemas[i]);
+      }
+    } finally {
+      AutoCloseables.close(states);
+    }
+  }
+
+  /**
+   * Export the aggregate result into structs allocated by the native library
+   *
+   * @param arrays address of the array struct to export into, as a single element array
+   * @param schemas address of the schema struct to export into, as a single element array
+   * @throws Exception if evaluating fails
+   */
+  void evaluate(long[] arrays, long[] schemas) throws Exception {
+    try (FieldVector result = accumulator.evaluate(allocator)) {
+      ArrowVectors.exportVector(allocator, result, arrays[0], schemas[0]);
+    }
+  }
+
+  /**
+   * Close the accumulator once the native library no longer uses it
+   *
+   * @throws Exception if closing fails
+   */
+  void close() throws Exception {
+    accumulator.close();
+  }
+}
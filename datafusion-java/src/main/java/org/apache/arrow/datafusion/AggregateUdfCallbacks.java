@@ -0,0 +1,24 @@
+package org.apache.arrow.datafusion;
+
+import java.util.function.Supplier;
+import org.apache.arrow.memory.BufferAllocator;
+
+/** Methods called from the native library to create accumulators for an aggregate UDF */
+final class AggregateUdfCallbacks {
+  private final BufferAllocator allocator;
+  private final Supplier<Accumulator> accumulatorFactory;
+
+  AggregateUdfCallbacks(BufferAllocator allocator, Supplier<Accumulator> accumulatorFactory) {
+    this.allocator = allocator;
+    this.accumulatorFactory = accumulatorFactory;
+  }
+
+  /**
+   * Create a new accumulator
+   *
+   * @return callbacks for the new accumulator
+   */
+  AccumulatorCallbacks createAccumulator() {
+    return new AccumulatorCallbacks(allocator, accumulatorFactory.get());
+  }
+}
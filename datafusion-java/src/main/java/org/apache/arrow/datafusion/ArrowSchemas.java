@@ -0,0 +1,65 @@
+package org.apache.arrow.datafusion;
+
+import java.util.ArrayList;
+import java.util.List;
+import java.util.function.LongFunction;
+import org.apache.arrow.c.ArrowSchema;
+import org.apache.arrow.c.CDataDictionaryProvider;
+import org.apache.arrow.c.Data;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.apache.arrow.vector.types.pojo.Field;
+import org.apache.arrow.vector.types.pojo.Schema;
+
+/** Helper methods for passing Arrow schemas to and from the native library */
+final class ArrowSchemas {
+
+  private ArrowSchemas() {}
+
+  /**
+   * Export a schema using the Arrow C data interface and pass its address to a native method. The
+   * exported schema is released once the native method returns, so the native method must copy it.
+   *
+   * @param schema the schema to export
+   * @param nativeMethod native method that accepts the address of the exported schema
+   * @param <T> the type returned by the native method
+   * @return the result of the native method
+   */
+  static <T> T withExportedSchema(Schema schema, LongFunction<T> nativeMethod) {
+    try (BufferAllocator allocator = new RootAllocator();
+        ArrowSchema arrowSchema = ArrowSchema.allocateNew(allocator)) {
+      Data.exportSchema(allocator, schema, null, arrowSchema);
+      return nativeMethod.apply(arrowSchema.memoryAddress());
+    }
+  }
+
+  /**
+   * Import a schema that was exported by the native library using the Arrow C data interface
+   *
+   * @param address the address of the exported schema
+   * @return the imported schema
+   */
+  static Schema importSchema(long address) {
+    try (BufferAllocator allocator = new RootAllocator();
+        CDataDictionaryProvider dictionaryProvider = new CDataDictionaryProvider()) {
+      ArrowSchema arrowSchema = ArrowSchema.wrap(address);
+      return Data.importSchema(allocator, arrowSchema, dictionaryProvider);
+    }
+  }
+
+  /**
+   * Create a schema with one nullable field per data type, used to pass a list of data types to
+   * the native library
+   *
+   * @param types the data types
+   * @return a schema with a field for each data type
+   */
+  static Schema typesSchema(List<ArrowType> types) {
+    List<Field> fields = new ArrayList<>(types.size());
+    for (int i = 0; i < types.size(); ++i) {
+      fields.add(Field.nullable("c" + i, types.get(i)));
+    }
+    return new Schema(fields);
+  }
+}
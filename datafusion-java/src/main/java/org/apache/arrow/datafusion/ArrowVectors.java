@@ -0,0 +1,54 @@
+package org.apache.arrow.datafusion;
+
+import java.util.ArrayList;
+import java.util.List;
+import org.apache.arrow.c.ArrowArray;
+import org.apache.arrow.c.ArrowSchema;
+import org.apache.arrow.c.Data;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.util.AutoCloseables;
+import org.apache.arrow.vector.FieldVector;
+
+/** Helper methods for passing Arrow vectors to and from the native library */
+final class ArrowVectors {
+
+  private ArrowVectors() {}
+
+  /**
+   * Import vectors that were exported by the native library using the Arrow C data interface,
+   * taking ownership of their data
+   *
+   * @param allocator the allocator to import the vectors with
+   * @param arrays addresses of the exported arrays
+   * @param schemas addresses of the exported schemas
+   * @return the imported vectors, which must be closed by the caller
+   * @throws Exception if importing fails
+   */
+  static List<FieldVector> importVectors(BufferAllocator allocator, long[] arrays, long[] schemas)
+      throws Exception {
+    List<FieldVector> vectors = new ArrayList<>(arrays.length);
+    try {
+      for (int i = 0; i < arrays.length; ++i) {
+        vectors.add(
+            Data.importVector(
+                allocator, ArrowArray.wrap(arrays[i]), ArrowSchema.wrap(schemas[i]), null));
+      }
+    } catch (Exception e) {
+      AutoCloseables.close(vectors);
+      throw e;
+    }
+    return vectors;
+  }
+
+  /**
+   * Export a vector into array and schema structs allocated by the native library
+   *
+   * @param allocator the allocator to export the vector with
+   * @param vector the vector to export, which can be closed once this method returns
+   * @param array address of the array struct to export into
+   * @param schema address of the schema struct to export into
+   */
+  static void exportVector(BufferAllocator allocator, FieldVector vector, long array, long schema) {
+    Data.exportVector(allocator, vector, null, ArrowArray.wrap(array), ArrowSchema.wrap(schema));
+  }
+}
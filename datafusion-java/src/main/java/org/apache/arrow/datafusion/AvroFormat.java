@@ -0,0 +1,18 @@
+package org.apache.arrow.datafusion;
+
+/** The Avro file format configuration */
+public class AvroFormat extends AbstractProxy implements FileFormat {
+  /** Create new Avro format with default options */
+  public AvroFormat() {
+    super(create());
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native long create();
+
+  private static native void destroy(long pointer);
+}
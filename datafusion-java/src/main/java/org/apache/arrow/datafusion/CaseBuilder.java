@@ -0,0 +1,51 @@
+package org.apache.arrow.datafusion;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * Builder for a CASE expression, created with {@link Expr#when(Expr, Expr)} or {@link
+ * Expr#caseOf(Expr)}. The builder holds references to the expressions it is given, which must not
+ * be closed before the CASE expression is built.
+ */
+public class CaseBuilder {
+  private final Expr base;
+  private final List<Expr> whenExprs = new ArrayList<>();
+  private final List<Expr> thenExprs = new ArrayList<>();
+
+  CaseBuilder(Expr base) {
+    this.base = base;
+  }
+
+  /**
+   * Add a branch to the CASE expression
+   *
+   * @param when the condition, or the value to compare with when the CASE has a base expression
+   * @param then the result of the branch
+   * @return this builder
+   */
+  public CaseBuilder when(Expr when, Expr then) {
+    whenExprs.add(when);
+    thenExprs.add(then);
+    return this;
+  }
+
+  /**
+   * Build the CASE expression with a result for when no branch matches
+   *
+   * @param otherwise the result when no branch matches
+   * @return the CASE expression
+   */
+  public Expr otherwise(Expr otherwise) {
+    return Expr.caseWhen(base, whenExprs, thenExprs, otherwise);
+  }
+
+  /**
+   * Build the CASE expression, which evaluates to null when no branch matches
+   *
+   * @return the CASE expression
+   */
+  public Expr end() {
+    return Expr.caseWhen(base, whenExprs, thenExprs, null);
+  }
+}
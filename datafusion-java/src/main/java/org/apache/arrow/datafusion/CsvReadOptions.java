@@ -0,0 +1,111 @@
+package org.apache.arrow.datafusion;
+
+import org.apache.arrow.vector.types.pojo.Schema;
+
+/** Options for reading CSV data into a table or {@link DataFrame} */
+@SuppressWarnings("UnusedReturnValue")
+public class CsvReadOptions extends AbstractProxy implements AutoCloseable {
+  /** Create new CSV read options with default values */
+  public CsvReadOptions() {
+    super(create());
+  }
+
+  /**
+   * Set whether the CSV data has a header row
+   *
+   * @param hasHeader whether the data has a header
+   * @return the modified {@link CsvReadOptions} instance
+   */
+  public CsvReadOptions withHasHeader(boolean hasHeader) {
+    setHasHeader(getPointer(), hasHeader);
+    return this;
+  }
+
+  /**
+   * Set the character used to separate fields
+   *
+   * @param delimiter an ASCII delimiter character
+   * @return the modified {@link CsvReadOptions} instance
+   */
+  public CsvReadOptions withDelimiter(char delimiter) {
+    setDelimiter(getPointer(), asciiByte(delimiter, "delimiter"));
+    return this;
+  }
+
+  /**
+   * Set the maximum number of records to read when inferring the schema
+   *
+   * @param maxRecords the maximum number of records
+   * @return the modified {@link CsvReadOptions} instance
+   */
+  public CsvReadOptions withSchemaInferMaxRecords(long maxRecords) {
+    if (maxRecords < 0) {
+      throw new IllegalArgumentException("maxRecords cannot be negative");
+    }
+    setSchemaInferMaxRecords(getPointer(), maxRecords);
+    return this;
+  }
+
+  /**
+   * Set the file extension used to filter files when reading a directory. Defaults to ".csv"
+   *
+   * @param fileExtension the file extension to filter on
+   * @return the modified {@link CsvReadOptions} instance
+   */
+  public CsvReadOptions withFileExtension(String fileExtension) {
+    setFileExtension(getPointer(), fileExtension);
+    return this;
+  }
+
+  /**
+   * Set the type of compression used by the CSV files
+   *
+   * @param compressionType the file compression type
+   * @return the modified {@link CsvReadOptions} instance
+   */
+  public CsvReadOptions withFileCompressionType(FileCompressionType compressionType) {
+    checkError(setFileCompressionType(getPointer(), compressionType.getId()));
+    return this;
+  }
+
+  /**
+   * Set the schema of the CSV data, rather than inferring it from the data
+   *
+   * @param schema the schema to use
+   * @return the modified {@link CsvReadOptions} instance
+   */
+  public CsvReadOptions withSchema(Schema schema) {
+    checkError(
+        ArrowSchemas.withExportedSchema(schema, address -> setSchema(getPointer(), address)));
+    return this;
+  }
+
+  private static byte asciiByte(char value, String name) {
+    if (value > 127) {
+      throw new IllegalArgumentException(name + " must be an ASCII character");
+    }
+    return (byte) value;
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native long create();
+
+  private static native void destroy(long pointer);
+
+  private static native void setHasHeader(long pointer, boolean hasHeader);
+
+  private static native void setDelimiter(long pointer, byte delimiter);
+
+  private static native void setSchemaInferMaxRecords(long pointer, long maxRecords);
+
+  private static native void setFileExtension(long pointer, String fileExtension);
+
+  private static native DataFusionException setFileCompressionType(
+      long pointer, int compressionTypeId);
+
+  private static native DataFusionException setSchema(long pointer, long schemaAddress);
+}
@@ -0,0 +1,34 @@
+package org.apache.arrow.datafusion;
+
+import org.apache.arrow.memory.BufferAllocator;
+
+/**
+ * A table backed by a {@link CustomTableProvider} implemented in Java. The allocator must not be
+ * closed before this table and any queries that use it are closed.
+ */
+public class CustomTable extends AbstractProxy implements TableProvider {
+  /**
+   * Create a new custom table
+   *
+   * @param allocator the allocator used to export schemas and scanned data
+   * @param provider the provider that implements the table
+   */
+  public CustomTable(BufferAllocator allocator, CustomTableProvider provider) {
+    super(createCustomTable(new TableProviderCallbacks(allocator, provider)));
+  }
+
+  private static long createCustomTable(TableProviderCallbacks callbacks) {
+    ObjectResult result = new ObjectResult();
+    create(callbacks, result);
+    return result.getObjectId();
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native void create(TableProviderCallbacks callbacks, ObjectResult result);
+
+  private static native void destroy(long pointer);
+}
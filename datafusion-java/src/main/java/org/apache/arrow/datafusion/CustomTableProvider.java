@@ -0,0 +1,50 @@
+package org.apache.arrow.datafusion;
+
+import java.util.List;
+import java.util.Optional;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.pojo.Schema;
+
+/**
+ * A data source implemented in Java. Wrap an implementation in a {@link CustomTable} to register it
+ * with a {@link SessionContext}. Methods may be called from native threads while queries execute,
+ * so implementations must be thread safe.
+ */
+public interface CustomTableProvider {
+  /**
+   * Get the schema of the table
+   *
+   * @return the table schema
+   */
+  Schema schema();
+
+  /**
+   * Scan the table data
+   *
+   * @param allocator the allocator to use for the returned data
+   * @param projection the indices of the columns to return, in the order they should be returned,
+   *     or empty to return all columns
+   * @param filters filters that may be used to skip data, as allowed by {@link
+   *     #supportsFilterPushDown(Expr)}. The filters are closed once this method returns.
+   * @param limit the maximum number of rows that need to be returned, or empty for no limit
+   * @return a reader providing the table data, which is closed once the scan completes
+   * @throws Exception if the scan fails
+   */
+  ArrowReader scan(
+      BufferAllocator allocator,
+      Optional<int[]> projection,
+      List<Expr> filters,
+      Optional<Long> limit)
+      throws Exception;
+
+  /**
+   * Get how a filter is handled when pushed down into a scan. By default filters are not used.
+   *
+   * @param filter the filter expression, which is closed once this method returns
+   * @return how the filter is handled
+   */
+  default FilterPushDown supportsFilterPushDown(Expr filter) {
+    return FilterPushDown.UNSUPPORTED;
+  }
+}
@@ -1,9 +1,12 @@
 package org.apache.arrow.datafusion;
 
 import java.nio.file.Path;
+import java.util.List;
+import java.util.Optional;
 import java.util.concurrent.CompletableFuture;
 import org.apache.arrow.memory.BufferAllocator;
 import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.pojo.Schema;
 
 /**
  * A dataframe is a rectangle shaped data that holds columns and rows, and can be {@link
@@ -11,7 +14,10 @@ import org.apache.arrow.vector.ipc.ArrowReader;
  * org.apache.arrow.vector.ipc.message.ArrowRecordBatch batches} and read via {@link ArrowReader
  * reader}.
  */
-public interface DataFrame extends NativeProxy {
+public interface DataFrame extends AutoCloseable, NativeProxy {
+  /** Field metadata key that holds the table qualifier of a column in {@link #getSchema()} */
+  String QUALIFIER_METADATA_KEY = "datafusion.qualifier";
+
   /**
    * Collect dataframe into a list of record batches
    *
@@ -60,4 +66,157 @@ public interface DataFrame extends NativeProxy {
    * @return null
    */
   CompletableFuture<Void> registerTable(SessionContext context, String name);
+
+  /**
+   * Create a new DataFrame containing only the named columns of this DataFrame
+   *
+   * @param columns the names of the columns to select
+   * @return the new DataFrame
+   */
+  DataFrame selectColumns(String... columns);
+
+  /**
+   * Create a new DataFrame by evaluating expressions against this DataFrame
+   *
+   * @param exprs the expressions to evaluate
+   * @return the new DataFrame
+   */
+  DataFrame select(List<Expr> exprs);
+
+  /**
+   * Create a new DataFrame containing only the rows of this DataFrame that match a predicate
+   *
+   * @param predicate a boolean expression used to filter rows
+   * @return the new DataFrame
+   */
+  DataFrame filter(Expr predicate);
+
+  /**
+   * Create a new DataFrame by grouping this DataFrame and computing aggregates for each group
+   *
+   * @param groupExprs the expressions to group by, which may be empty to aggregate all rows
+   * @param aggregateExprs the aggregate expressions to compute
+   * @return the new DataFrame
+   */
+  DataFrame aggregate(List<Expr> groupExprs, List<Expr> aggregateExprs);
+
+  /**
+   * Create a new DataFrame by sorting this DataFrame
+   *
+   * @param sortExprs sort expressions created with {@link Expr#sort(boolean, boolean)}
+   * @return the new DataFrame
+   */
+  DataFrame sort(List<Expr> sortExprs);
+
+  /**
+   * Create a new DataFrame containing at most {@code fetch} rows of this DataFrame
+   *
+   * @param fetch the maximum number of rows to return
+   * @return the new DataFrame
+   */
+  DataFrame limit(long fetch);
+
+  /**
+   * Create a new DataFrame that skips rows of this DataFrame and limits the number of rows returned
+   *
+   * @param skip the number of rows to skip
+   * @param fetch the maximum number of rows to return, or empty to return all remaining rows
+   * @return the new DataFrame
+   */
+  DataFrame limit(long skip, Optional<Long> fetch);
+
+  /**
+   * Create a new DataFrame containing only the distinct rows of this DataFrame
+   *
+   * @return the new DataFrame
+   */
+  DataFrame distinct();
+
+  /**
+   * Join this DataFrame with another DataFrame using equality of key columns
+   *
+   * @param right the DataFrame to join with
+   * @param joinType the type of join
+   * @param leftColumns the key columns of this DataFrame
+   * @param rightColumns the key columns of the right DataFrame, matched by position with {@code
+   *     leftColumns}
+   * @return the joined DataFrame
+   */
+  DataFrame join(
+      DataFrame right, JoinType joinType, List<String> leftColumns, List<String> rightColumns);
+
+  /**
+   * Join this DataFrame with another DataFrame using equality of key columns and an additional
+   * filter expression
+   *
+   * @param right the DataFrame to join with
+   * @param joinType the type of join
+   * @param leftColumns the key columns of this DataFrame
+   * @param rightColumns the key columns of the right DataFrame, matched by position with {@code
+   *     leftColumns}
+   * @param filter a filter that joined rows must also satisfy
+   * @return the joined DataFrame
+   */
+  DataFrame join(
+      DataFrame right,
+      JoinType joinType,
+      List<String> leftColumns,
+      List<String> rightColumns,
+      Expr filter);
+
+  /**
+   * Join this DataFrame with another DataFrame using arbitrary join expressions. Equality
+   * expressions between columns of the two DataFrames are used as join keys where possible.
+   *
+   * @param right the DataFrame to join with
+   * @param joinType the type of join
+   * @param onExprs the join expressions, which are combined with AND
+   * @return the joined DataFrame
+   */
+  DataFrame joinOn(DataFrame right, JoinType joinType, List<Expr> onExprs);
+
+  /**
+   * Get the schema of this DataFrame without executing it. Fields that belong to a table have the
+   * table name stored in their metadata under {@link #QUALIFIER_METADATA_KEY}.
+   *
+   * @return the schema of this DataFrame
+   */
+  Schema getSchema();
+
+  /**
+   * Create a new DataFrame containing the rows of this DataFrame followed by the rows of another
+   * DataFrame, without removing duplicates. Both DataFrames must have the same number of columns
+   * with compatible types.
+   *
+   * @param other the DataFrame to union with
+   * @return the new DataFrame
+   */
+  DataFrame union(DataFrame other);
+
+  /**
+   * Create a new DataFrame containing the distinct rows of this DataFrame and another DataFrame.
+   * Both DataFrames must have the same number of columns with compatible types.
+   *
+   * @param other the DataFrame to union with
+   * @return the new DataFrame
+   */
+  DataFrame unionDistinct(DataFrame other);
+
+  /**
+   * Create a new DataFrame containing the rows of this DataFrame that also appear in another
+   * DataFrame. Both DataFrames must have the same number of columns with compatible types.
+   *
+   * @param other the DataFrame to intersect with
+   * @return the new DataFrame
+   */
+  DataFrame intersect(DataFrame other);
+
+  /**
+   * Create a new DataFrame containing the rows of this DataFrame that do not appear in another
+   * DataFrame. Both DataFrames must have the same number of columns with compatible types.
+   *
+   * @param other the DataFrame containing rows to exclude
+   * @return the new DataFrame
+   */
+  DataFrame except(DataFrame other);
 }
@@ -10,18 +10,62 @@ final class DataFrames {
 
   static native void destroyDataFrame(long pointer);
 
-  static native void showDataframe(long runtime, long dataframe, Consumer<String> callback);
+  static native long showDataframe(
+      long runtime, long dataframe, Consumer<DataFusionException> callback);
 
-  static native void collectDataframe(
-      long runtime, long dataframe, BiConsumer<String, byte[]> callback);
+  static native long collectDataframe(
+      long runtime, long dataframe, BiConsumer<DataFusionException, byte[]> callback);
 
-  static native void executeStream(long runtime, long dataframe, ObjectResultCallback callback);
+  static native long executeStream(long runtime, long dataframe, ObjectResultCallback callback);
 
-  static native void writeParquet(
-      long runtime, long dataframe, String path, Consumer<String> callback);
+  static native long writeParquet(
+      long runtime, long dataframe, String path, Consumer<DataFusionException> callback);
 
-  static native void writeCsv(long runtime, long dataframe, String path, Consumer<String> callback);
+  static native long writeCsv(
+      long runtime, long dataframe, String path, Consumer<DataFusionException> callback);
 
-  static native void registerTable(
-      long runtime, long dataframe, long context, String name, Consumer<String> callback);
+  static native long registerTable(
+      long runtime,
+      long dataframe,
+      long context,
+      String name,
+      Consumer<DataFusionException> callback);
+
+  static native void selectColumns(long dataframe, String[] columns, ObjectResult result);
+
+  static native void select(long dataframe, long[] exprs, ObjectResult result);
+
+  static native void filter(long dataframe, long predicate, ObjectResult result);
+
+  static native void aggregate(
+      long dataframe, long[] groupExprs, long[] aggregateExprs, ObjectResult result);
+
+  static native void sort(long dataframe, long[] sortExprs, ObjectResult result);
+
+  static native void limit(
+      long dataframe, long skip, boolean hasFetch, long fetch, ObjectResult result);
+
+  static native void distinct(long dataframe, ObjectResult result);
+
+  static native void join(
+      long left,
+      long right,
+      int joinType,
+      String[] leftColumns,
+      String[] rightColumns,
+      long filter,
+      ObjectResult result);
+
+  static native void joinOn(
+      long left, long right, int joinType, long[] onExprs, ObjectResult result);
+
+  static native void getSchema(long dataframe, ObjectResultCallback callback);
+
+  static native void union(long left, long right, ObjectResult result);
+
+  static native void unionDistinct(long left, long right, ObjectResult result);
+
+  static native void intersect(long left, long right, ObjectResult result);
+
+  static native void except(long left, long right, ObjectResult result);
 }
@@ -0,0 +1,51 @@
+package org.apache.arrow.datafusion;
+
+/**
+ * An error reported by the native DataFusion library. Errors caused by a mistake in the query are
+ * thrown as {@link QueryException}, and errors from running out of memory or other resources as
+ * {@link ResourcesExhaustedException}.
+ */
+public class DataFusionException extends RuntimeException {
+  private final ErrorKind kind;
+
+  /**
+   * Create a new exception
+   *
+   * @param kind the kind of error
+   * @param message the error message
+   * @param cause the underlying cause of the error, or null
+   */
+  public DataFusionException(ErrorKind kind, String message, Throwable cause) {
+    super(message, cause);
+    this.kind = kind;
+  }
+
+  /**
+   * Get the kind of error
+   *
+   * @return the error kind
+   */
+  public ErrorKind getKind() {
+    return kind;
+  }
+
+  /**
+   * Create an exception for an error from the native library, using the most specific exception
+   * class for the error kind
+   *
+   * @param kindId the error kind identifier
+   * @param message the error message
+   * @param cause the underlying cause of the error, or null
+   * @return the new exception
+   */
+  static DataFusionException create(int kindId, String message, Throwable cause) {
+    ErrorKind kind = ErrorKind.fromId(kindId);
+    if (kind.isQueryError()) {
+      return new QueryException(kind, message, cause);
+    } else if (kind == ErrorKind.RESOURCES_EXHAUSTED) {
+      return new ResourcesExhaustedException(message, cause);
+    } else {
+      return new DataFusionException(kind, message, cause);
+    }
+  }
+}
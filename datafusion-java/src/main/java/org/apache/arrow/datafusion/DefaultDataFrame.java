@@ -1,10 +1,14 @@
 package org.apache.arrow.datafusion;
 
 import java.nio.file.Path;
+import java.util.List;
+import java.util.Optional;
 import java.util.concurrent.CompletableFuture;
+import java.util.function.Consumer;
 import org.apache.arrow.memory.BufferAllocator;
 import org.apache.arrow.vector.ipc.ArrowFileReader;
 import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.pojo.Schema;
 import org.apache.arrow.vector.util.ByteArrayReadableSeekableByteChannel;
 import org.slf4j.Logger;
 import org.slf4j.LoggerFactory;
@@ -21,65 +25,65 @@ class DefaultDataFrame extends AbstractProxy implements DataFrame {
 
   @Override
   public CompletableFuture<ArrowReader> collect(BufferAllocator allocator) {
-    CompletableFuture<ArrowReader> result = new CompletableFuture<>();
+    NativeFuture<ArrowReader> result = new NativeFuture<>();
     Runtime runtime = context.getRuntime();
     long runtimePointer = runtime.getPointer();
     long dataframe = getPointer();
-    DataFrames.collectDataframe(
-        runtimePointer,
-        dataframe,
-        (String errString, byte[] arr) -> {
-          if (containsError(errString)) {
-            result.completeExceptionally(new RuntimeException(errString));
-          } else {
-            logger.info("successfully completed with arr length={}", arr.length);
-            ByteArrayReadableSeekableByteChannel byteChannel =
-                new ByteArrayReadableSeekableByteChannel(arr);
-            result.complete(new ArrowFileReader(byteChannel, allocator));
-          }
-        });
+    result.setTask(
+        DataFrames.collectDataframe(
+            runtimePointer,
+            dataframe,
+            (DataFusionException error, byte[] arr) -> {
+              if (error != null) {
+                result.completeExceptionally(error);
+              } else {
+                logger.info("successfully completed with arr length={}", arr.length);
+                ByteArrayReadableSeekableByteChannel byteChannel =
+                    new ByteArrayReadableSeekableByteChannel(arr);
+                NativeFuture.completeOrClose(result, new ArrowFileReader(byteChannel, allocator));
+              }
+            }));
     return result;
   }
 
   @Override
   public CompletableFuture<RecordBatchStream> executeStream(BufferAllocator allocator) {
-    CompletableFuture<RecordBatchStream> result = new CompletableFuture<>();
+    NativeFuture<RecordBatchStream> result = new NativeFuture<>();
     Runtime runtime = context.getRuntime();
     long runtimePointer = runtime.getPointer();
     long dataframe = getPointer();
-    DataFrames.executeStream(
-        runtimePointer,
-        dataframe,
-        (errString, streamId) -> {
-          if (containsError(errString)) {
-            result.completeExceptionally(new RuntimeException(errString));
-          } else {
-            result.complete(new DefaultRecordBatchStream(context, streamId, allocator));
-          }
-        });
+    result.setTask(
+        DataFrames.executeStream(
+            runtimePointer,
+            dataframe,
+            (error, streamId) -> {
+              if (error != null) {
+                result.completeExceptionally(error);
+              } else {
+                NativeFuture.completeOrClose(
+                    result, new DefaultRecordBatchStream(context, streamId, allocator));
+              }
+            }));
     return result;
   }
 
-  private boolean containsError(String errString) {
-    return errString != null && !"".equals(errString);
-  }
-
   @Override
   public CompletableFuture<Void> show() {
     Runtime runtime = context.getRuntime();
     long runtimePointer = runtime.getPointer();
     long dataframe = getPointer();
-    CompletableFuture<Void> future = new CompletableFuture<>();
-    DataFrames.showDataframe(
-        runtimePointer,
-        dataframe,
-        (String errString) -> {
-          if (containsError(errString)) {
-            future.completeExceptionally(new RuntimeException(errString));
-          } else {
-            future.complete(null);
-          }
-        });
+    NativeFuture<Void> future = new NativeFuture<>();
+    future.setTask(
+        DataFrames.showDataframe(
+            runtimePointer,
+            dataframe,
+            (DataFusionException error) -> {
+              if (error != null) {
+                future.completeExceptionally(error);
+              } else {
+                future.complete(null);
+              }
+            }));
     return future;
   }
 
@@ -88,18 +92,19 @@ class DefaultDataFrame extends AbstractProxy implements DataFrame {
     Runtime runtime = context.getRuntime();
     long runtimePointer = runtime.getPointer();
     long dataframe = getPointer();
-    CompletableFuture<Void> future = new CompletableFuture<>();
-    DataFrames.writeParquet(
-        runtimePointer,
-        dataframe,
-        path.toAbsolutePath().toString(),
-        (String errString) -> {
-          if (containsError(errString)) {
-            future.completeExceptionally(new RuntimeException(errString));
-          } else {
-            future.complete(null);
-          }
-        });
+    NativeFuture<Void> future = new NativeFuture<>();
+    future.setTask(
+        DataFrames.writeParquet(
+            runtimePointer,
+            dataframe,
+            path.toAbsolutePath().toString(),
+            (DataFusionException error) -> {
+              if (error != null) {
+                future.completeExceptionally(error);
+              } else {
+                future.complete(null);
+              }
+            }));
     return future;
   }
 
@@ -108,18 +113,19 @@ class DefaultDataFrame extends AbstractProxy implements DataFrame {
     Runtime runtime = context.getRuntime();
     long runtimePointer = runtime.getPointer();
     long dataframe = getPointer();
-    CompletableFuture<Void> future = new CompletableFuture<>();
-    DataFrames.writeCsv(
-        runtimePointer,
-        dataframe,
-        path.toAbsolutePath().toString(),
-        (String errString) -> {
-          if (containsError(errString)) {
-            future.completeExceptionally(new RuntimeException(errString));
-          } else {
-            future.complete(null);
-          }
-        });
+    NativeFuture<Void> future = new NativeFuture<>();
+    future.setTask(
+        DataFrames.writeCsv(
+            runtimePointer,
+            dataframe,
+            path.toAbsolutePath().toString(),
+            (DataFusionException error) -> {
+              if (error != null) {
+                future.completeExceptionally(error);
+              } else {
+                future.complete(null);
+              }
+            }));
     return future;
   }
 
@@ -128,20 +134,165 @@ class DefaultDataFrame extends AbstractProxy implements DataFrame {
     long runtimePointer = runtime.getPointer();
     long dataframe = getPointer();
     long contextPointer = ctx.getPointer();
-    CompletableFuture<Void> future = new CompletableFuture<>();
-    DataFrames.registerTable(
-        runtimePointer,
-        dataframe,
-        contextPointer,
-        name,
-        (String errString) -> {
-          if (containsError(errString)) {
-            future.completeExceptionally(new RuntimeException(errString));
+    NativeFuture<Void> future = new NativeFuture<>();
+    future.setTask(
+        DataFrames.registerTable(
+            runtimePointer,
+            dataframe,
+            contextPointer,
+            name,
+            (DataFusionException error) -> {
+              if (error != null) {
+                future.completeExceptionally(error);
+              } else {
+                future.complete(null);
+              }
+            }));
+    return future;
+  }
+
+  @Override
+  public DataFrame selectColumns(String... columns) {
+    return transform(result -> DataFrames.selectColumns(getPointer(), columns, result));
+  }
+
+  @Override
+  public DataFrame select(List<Expr> exprs) {
+    return transform(result -> DataFrames.select(getPointer(), Expr.pointers(exprs), result));
+  }
+
+  @Override
+  public DataFrame filter(Expr predicate) {
+    return transform(result -> DataFrames.filter(getPointer(), predicate.getPointer(), result));
+  }
+
+  @Override
+  public DataFrame aggregate(List<Expr> groupExprs, List<Expr> aggregateExprs) {
+    return transform(
+        result ->
+            DataFrames.aggregate(
+                getPointer(), Expr.pointers(groupExprs), Expr.pointers(aggregateExprs), result));
+  }
+
+  @Override
+  public DataFrame sort(List<Expr> sortExprs) {
+    return transform(result -> DataFrames.sort(getPointer(), Expr.pointers(sortExprs), result));
+  }
+
+  @Override
+  public DataFrame limit(long fetch) {
+    return limit(0, Optional.of(fetch));
+  }
+
+  @Override
+  public DataFrame limit(long skip, Optional<Long> fetch) {
+    if (skip < 0 || fetch.orElse(0L) < 0) {
+      throw new IllegalArgumentException("skip and fetch cannot be negative");
+    }
+    return transform(
+        result ->
+            DataFrames.limit(getPointer(), skip, fetch.isPresent(), fetch.orElse(0L), result));
+  }
+
+  @Override
+  public DataFrame distinct() {
+    return transform(result -> DataFrames.distinct(getPointer(), result));
+  }
+
+  @Override
+  public DataFrame join(
+      DataFrame right, JoinType joinType, List<String> leftColumns, List<String> rightColumns) {
+    return join(right, joinType, leftColumns, rightColumns, 0);
+  }
+
+  @Override
+  public DataFrame join(
+      DataFrame right,
+      JoinType joinType,
+      List<String> leftColumns,
+      List<String> rightColumns,
+      Expr filter) {
+    return join(right, joinType, leftColumns, rightColumns, filter.getPointer());
+  }
+
+  private DataFrame join(
+      DataFrame right,
+      JoinType joinType,
+      List<String> leftColumns,
+      List<String> rightColumns,
+      long filter) {
+    if (leftColumns.size() != rightColumns.size()) {
+      throw new IllegalArgumentException("left and right join columns must have the same length");
+    }
+    return transform(
+        result ->
+            DataFrames.join(
+                getPointer(),
+                right.getPointer(),
+                joinType.getId(),
+                leftColumns.toArray(new String[0]),
+                rightColumns.toArray(new String[0]),
+                filter,
+                result));
+  }
+
+  @Override
+  public DataFrame joinOn(DataFrame right, JoinType joinType, List<Expr> onExprs) {
+    return transform(
+        result ->
+            DataFrames.joinOn(
+                getPointer(),
+                right.getPointer(),
+                joinType.getId(),
+                Expr.pointers(onExprs),
+                result));
+  }
+
+  @Override
+  public Schema getSchema() {
+    // Native method is not async, but use a future to store the result for convenience
+    CompletableFuture<Schema> result = new CompletableFuture<>();
+    DataFrames.getSchema(
+        getPointer(),
+        (error, arrowSchemaAddress) -> {
+          if (error != null) {
+            result.completeExceptionally(error);
           } else {
-            future.complete(null);
+            try {
+              result.complete(ArrowSchemas.importSchema(arrowSchemaAddress));
+              // The FFI schema will be released from rust when it is dropped
+            } catch (Exception e) {
+              result.completeExceptionally(e);
+            }
           }
         });
-    return future;
+    return result.join();
+  }
+
+  @Override
+  public DataFrame union(DataFrame other) {
+    return transform(result -> DataFrames.union(getPointer(), other.getPointer(), result));
+  }
+
+  @Override
+  public DataFrame unionDistinct(DataFrame other) {
+    return transform(result -> DataFrames.unionDistinct(getPointer(), other.getPointer(), result));
+  }
+
+  @Override
+  public DataFrame intersect(DataFrame other) {
+    return transform(result -> DataFrames.intersect(getPointer(), other.getPointer(), result));
+  }
+
+  @Override
+  public DataFrame except(DataFrame other) {
+    return transform(result -> DataFrames.except(getPointer(), other.getPointer(), result));
+  }
+
+  private DataFrame transform(Consumer<ObjectResult> transformation) {
+    ObjectResult result = new ObjectResult();
+    transformation.accept(result);
+    return new DefaultDataFrame(context, result.getObjectId());
   }
 
   @Override
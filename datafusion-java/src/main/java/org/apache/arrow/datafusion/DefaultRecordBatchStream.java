@@ -46,30 +46,36 @@ class DefaultRecordBatchStream extends AbstractProxy implements RecordBatchStrea
     Runtime runtime = context.getRuntime();
     long runtimePointer = runtime.getPointer();
     long recordBatchStream = getPointer();
-    CompletableFuture<Boolean> result = new CompletableFuture<>();
-    next(
-        runtimePointer,
-        recordBatchStream,
-        (errString, arrowArrayAddress) -> {
-          if (containsError(errString)) {
-            result.completeExceptionally(new RuntimeException(errString));
-          } else if (arrowArrayAddress == 0) {
-            // Reached end of stream
-            result.complete(false);
-          } else {
-            try {
-              ArrowArray arrowArray = ArrowArray.wrap(arrowArrayAddress);
-              Data.importIntoVectorSchemaRoot(
-                  allocator, arrowArray, vectorSchemaRoot, dictionaryProvider);
-              result.complete(true);
-            } catch (Exception e) {
-              result.completeExceptionally(e);
-            }
-          }
-        });
+    NativeFuture<Boolean> result = new NativeFuture<>();
+    result.setTask(
+        next(
+            runtimePointer,
+            recordBatchStream,
+            (error, arrowArrayAddress) -> {
+              if (error != null) {
+                result.completeExceptionally(error);
+              } else if (arrowArrayAddress == 0) {
+                // Reached end of stream
+                result.complete(false);
+              } else {
+                try {
+                  ArrowArray arrowArray = ArrowArray.wrap(arrowArrayAddress);
+                  Data.importIntoVectorSchemaRoot(
+                      allocator, arrowArray, vectorSchemaRoot, dictionaryProvider);
+                  result.complete(true);
+                } catch (Exception e) {
+                  result.completeExceptionally(e);
+                }
+              }
+            }));
     return result;
   }
 
+  @Override
+  public void cancel() {
+    cancel(getPointer());
+  }
+
   @Override
   public Dictionary lookup(long id) {
     return dictionaryProvider.lookup(id);
@@ -94,9 +100,9 @@ class DefaultRecordBatchStream extends AbstractProxy implements RecordBatchStrea
     CompletableFuture<Schema> result = new CompletableFuture<>();
     getSchema(
         recordBatchStream,
-        (errString, arrowSchemaAddress) -> {
-          if (containsError(errString)) {
-            result.completeExceptionally(new RuntimeException(errString));
+        (error, arrowSchemaAddress) -> {
+          if (error != null) {
+            result.completeExceptionally(error);
           } else {
             try {
               ArrowSchema arrowSchema = ArrowSchema.wrap(arrowSchemaAddress);
@@ -111,13 +117,11 @@ class DefaultRecordBatchStream extends AbstractProxy implements RecordBatchStrea
     return result.join();
   }
 
-  private static boolean containsError(String errString) {
-    return errString != null && !"".equals(errString);
-  }
-
   private static native void getSchema(long pointer, ObjectResultCallback callback);
 
-  private static native void next(long runtime, long pointer, ObjectResultCallback callback);
+  private static native long next(long runtime, long pointer, ObjectResultCallback callback);
+
+  private static native void cancel(long pointer);
 
   private static native void destroy(long pointer);
 }
@@ -1,8 +1,13 @@
 package org.apache.arrow.datafusion;
 
 import java.nio.file.Path;
+import java.util.Collections;
+import java.util.List;
 import java.util.concurrent.CompletableFuture;
 import java.util.function.Consumer;
+import java.util.function.Supplier;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.vector.types.pojo.ArrowType;
 import org.slf4j.Logger;
 import org.slf4j.LoggerFactory;
 
@@ -10,78 +15,354 @@ class DefaultSessionContext extends AbstractProxy implements SessionContext {
 
   private static final Logger logger = LoggerFactory.getLogger(DefaultSessionContext.class);
 
-  static native void querySql(
+  static native long querySql(
       long runtime, long context, String sql, ObjectResultCallback callback);
 
-  static native void registerCsv(
-      long runtime, long context, String name, String path, Consumer<String> callback);
+  static native long registerCsv(
+      long runtime,
+      long context,
+      String name,
+      String path,
+      long options,
+      Consumer<DataFusionException> callback);
 
-  static native void registerParquet(
-      long runtime, long context, String name, String path, Consumer<String> callback);
+  static native long readCsv(
+      long runtime, long context, String path, long options, ObjectResultCallback callback);
 
-  static native String registerTable(long context, String table_reference, long tableProvider);
+  static native long registerParquet(
+      long runtime,
+      long context,
+      String name,
+      String path,
+      long options,
+      Consumer<DataFusionException> callback);
+
+  static native long readParquet(
+      long runtime, long context, String path, long options, ObjectResultCallback callback);
+
+  static native long registerJson(
+      long runtime,
+      long context,
+      String name,
+      String path,
+      long options,
+      Consumer<DataFusionException> callback);
+
+  static native long readJson(
+      long runtime, long context, String path, long options, ObjectResultCallback callback);
+
+  static native long registerAvro(
+      long runtime,
+      long context,
+      String name,
+      String path,
+      Consumer<DataFusionException> callback);
+
+  static native long readAvro(
+      long runtime, long context, String path, ObjectResultCallback callback);
+
+  static native DataFusionException registerTable(
+      long context, String table_reference, long tableProvider);
+
+  static native DataFusionException registerUdf(
+      long context,
+      String name,
+      long argumentTypes,
+      long returnType,
+      int volatility,
+      ScalarUdfCallbacks callbacks);
+
+  static native DataFusionException registerUdaf(
+      long context,
+      String name,
+      long argumentTypes,
+      long returnType,
+      long stateTypes,
+      int volatility,
+      AggregateUdfCallbacks callbacks);
+
+  static native DataFusionException loadUdfLibrary(long context, String path);
+
+  static native long getSpillCount(long context);
+
+  static native long getSpilledBytes(long context);
 
   @Override
   public CompletableFuture<DataFrame> sql(String sql) {
     long runtime = getRuntime().getPointer();
-    CompletableFuture<DataFrame> future = new CompletableFuture<>();
-    querySql(
-        runtime,
-        getPointer(),
-        sql,
-        (errMessage, dataframeId) -> {
-          if (null != errMessage && !errMessage.equals("")) {
-            future.completeExceptionally(new RuntimeException(errMessage));
-          } else {
-            DefaultDataFrame frame = new DefaultDataFrame(DefaultSessionContext.this, dataframeId);
-            future.complete(frame);
-          }
-        });
+    NativeFuture<DataFrame> future = new NativeFuture<>();
+    future.setTask(querySql(runtime, getPointer(), sql, dataFrameCallback(future)));
     return future;
   }
 
   @Override
   public CompletableFuture<Void> registerCsv(String name, Path path) {
+    return registerCsv(name, path, 0);
+  }
+
+  @Override
+  public CompletableFuture<Void> registerCsv(String name, Path path, CsvReadOptions options) {
+    return registerCsv(name, path, options.getPointer());
+  }
+
+  private CompletableFuture<Void> registerCsv(String name, Path path, long options) {
     long runtime = getRuntime().getPointer();
-    CompletableFuture<Void> future = new CompletableFuture<>();
-    registerCsv(
-        runtime,
-        getPointer(),
-        name,
-        path.toAbsolutePath().toString(),
-        (errMessage) -> voidCallback(future, errMessage));
+    NativeFuture<Void> future = new NativeFuture<>();
+    future.setTask(
+        registerCsv(
+            runtime,
+            getPointer(),
+            name,
+            path.toAbsolutePath().toString(),
+            options,
+            (error) -> voidCallback(future, error)));
+    return future;
+  }
+
+  @Override
+  public CompletableFuture<DataFrame> readCsv(Path path) {
+    return readCsv(path, 0);
+  }
+
+  @Override
+  public CompletableFuture<DataFrame> readCsv(Path path, CsvReadOptions options) {
+    return readCsv(path, options.getPointer());
+  }
+
+  private CompletableFuture<DataFrame> readCsv(Path path, long options) {
+    long runtime = getRuntime().getPointer();
+    NativeFuture<DataFrame> future = new NativeFuture<>();
+    future.setTask(
+        readCsv(
+            runtime,
+            getPointer(),
+            path.toAbsolutePath().toString(),
+            options,
+            dataFrameCallback(future)));
     return future;
   }
 
   @Override
   public CompletableFuture<Void> registerParquet(String name, Path path) {
+    return registerParquet(name, path, 0);
+  }
+
+  @Override
+  public CompletableFuture<Void> registerParquet(
+      String name, Path path, ParquetReadOptions options) {
+    return registerParquet(name, path, options.getPointer());
+  }
+
+  private CompletableFuture<Void> registerParquet(String name, Path path, long options) {
+    long runtime = getRuntime().getPointer();
+    NativeFuture<Void> future = new NativeFuture<>();
+    future.setTask(
+        registerParquet(
+            runtime,
+            getPointer(),
+            name,
+            path.toAbsolutePath().toString(),
+            options,
+            (error) -> voidCallback(future, error)));
+    return future;
+  }
+
+  @Override
+  public CompletableFuture<DataFrame> readParquet(Path path) {
+    return readParquet(path, 0);
+  }
+
+  @Override
+  public CompletableFuture<DataFrame> readParquet(Path path, ParquetReadOptions options) {
+    return readParquet(path, options.getPointer());
+  }
+
+  private CompletableFuture<DataFrame> readParquet(Path path, long options) {
+    long runtime = getRuntime().getPointer();
+    NativeFuture<DataFrame> future = new NativeFuture<>();
+    future.setTask(
+        readParquet(
+            runtime,
+            getPointer(),
+            path.toAbsolutePath().toString(),
+            options,
+            dataFrameCallback(future)));
+    return future;
+  }
+
+  @Override
+  public CompletableFuture<Void> registerJson(String name, Path path) {
+    return registerJson(name, path, 0);
+  }
+
+  @Override
+  public CompletableFuture<Void> registerJson(String name, Path path, NdJsonReadOptions options) {
+    return registerJson(name, path, options.getPointer());
+  }
+
+  private CompletableFuture<Void> registerJson(String name, Path path, long options) {
     long runtime = getRuntime().getPointer();
-    CompletableFuture<Void> future = new CompletableFuture<>();
-    registerParquet(
-        runtime,
-        getPointer(),
-        name,
-        path.toAbsolutePath().toString(),
-        (errMessage) -> voidCallback(future, errMessage));
+    NativeFuture<Void> future = new NativeFuture<>();
+    future.setTask(
+        registerJson(
+            runtime,
+            getPointer(),
+            name,
+            path.toAbsolutePath().toString(),
+            options,
+            (error) -> voidCallback(future, error)));
+    return future;
+  }
+
+  @Override
+  public CompletableFuture<DataFrame> readJson(Path path) {
+    return readJson(path, 0);
+  }
+
+  @Override
+  public CompletableFuture<DataFrame> readJson(Path path, NdJsonReadOptions options) {
+    return readJson(path, options.getPointer());
+  }
+
+  private CompletableFuture<DataFrame> readJson(Path path, long options) {
+    long runtime = getRuntime().getPointer();
+    NativeFuture<DataFrame> future = new NativeFuture<>();
+    future.setTask(
+        readJson(
+            runtime,
+            getPointer(),
+            path.toAbsolutePath().toString(),
+            options,
+            dataFrameCallback(future)));
+    return future;
+  }
+
+  @Override
+  public CompletableFuture<Void> registerAvro(String name, Path path) {
+    long runtime = getRuntime().getPointer();
+    NativeFuture<Void> future = new NativeFuture<>();
+    future.setTask(
+        registerAvro(
+            runtime,
+            getPointer(),
+            name,
+            path.toAbsolutePath().toString(),
+            (error) -> voidCallback(future, error)));
+    return future;
+  }
+
+  @Override
+  public CompletableFuture<DataFrame> readAvro(Path path) {
+    long runtime = getRuntime().getPointer();
+    NativeFuture<DataFrame> future = new NativeFuture<>();
+    future.setTask(
+        readAvro(
+            runtime, getPointer(), path.toAbsolutePath().toString(), dataFrameCallback(future)));
     return future;
   }
 
   @Override
   public void registerTable(String table_reference, TableProvider tableProvider) {
-    String errorMessage = registerTable(getPointer(), table_reference, tableProvider.getPointer());
-    if (errorMessage != null && !errorMessage.isEmpty()) {
-      throw new RuntimeException(errorMessage);
+    DataFusionException error =
+        registerTable(getPointer(), table_reference, tableProvider.getPointer());
+    if (error != null) {
+      throw error;
+    }
+  }
+
+  @Override
+  public void registerUdf(
+      BufferAllocator allocator,
+      String name,
+      List<ArrowType> argumentTypes,
+      ArrowType returnType,
+      Volatility volatility,
+      ScalarUdf udf) {
+    ScalarUdfCallbacks callbacks = new ScalarUdfCallbacks(allocator, udf);
+    DataFusionException error =
+        ArrowSchemas.withExportedSchema(
+            ArrowSchemas.typesSchema(argumentTypes),
+            argumentTypesAddress ->
+                ArrowSchemas.withExportedSchema(
+                    ArrowSchemas.typesSchema(Collections.singletonList(returnType)),
+                    returnTypeAddress ->
+                        registerUdf(
+                            getPointer(),
+                            name,
+                            argumentTypesAddress,
+                            returnTypeAddress,
+                            volatility.getId(),
+                            callbacks)));
+    if (error != null) {
+      throw error;
+    }
+  }
+
+  @Override
+  public void registerUdaf(
+      BufferAllocator allocator,
+      String name,
+      List<ArrowType> argumentTypes,
+      ArrowType returnType,
+      List<ArrowType> stateTypes,
+      Volatility volatility,
+      Supplier<Accumulator> accumulatorFactory) {
+    AggregateUdfCallbacks callbacks = new AggregateUdfCallbacks(allocator, accumulatorFactory);
+    DataFusionException error =
+        ArrowSchemas.withExportedSchema(
+            ArrowSchemas.typesSchema(argumentTypes),
+            argumentTypesAddress ->
+                ArrowSchemas.withExportedSchema(
+                    ArrowSchemas.typesSchema(Collections.singletonList(returnType)),
+                    returnTypeAddress ->
+                        ArrowSchemas.withExportedSchema(
+                            ArrowSchemas.typesSchema(stateTypes),
+                            stateTypesAddress ->
+                                registerUdaf(
+                                    getPointer(),
+                                    name,
+                                    argumentTypesAddress,
+                                    returnTypeAddress,
+                                    stateTypesAddress,
+                                    volatility.getId(),
+                                    callbacks))));
+    if (error != null) {
+      throw error;
     }
   }
 
-  private void voidCallback(CompletableFuture<Void> future, String errMessage) {
-    if (null != errMessage && !errMessage.equals("")) {
-      future.completeExceptionally(new RuntimeException(errMessage));
+  @Override
+  public void loadUdfLibrary(Path path) {
+    DataFusionException error = loadUdfLibrary(getPointer(), path.toAbsolutePath().toString());
+    if (error != null) {
+      throw error;
+    }
+  }
+
+  private ObjectResultCallback dataFrameCallback(CompletableFuture<DataFrame> future) {
+    return (error, dataframeId) -> {
+      if (error != null) {
+        future.completeExceptionally(error);
+      } else {
+        DefaultDataFrame frame = new DefaultDataFrame(DefaultSessionContext.this, dataframeId);
+        NativeFuture.completeOrClose(future, frame);
+      }
+    };
+  }
+
+  private void voidCallback(CompletableFuture<Void> future, DataFusionException error) {
+    if (error != null) {
+      future.completeExceptionally(error);
     } else {
       future.complete(null);
     }
   }
 
+  @Override
+  public SpillMetrics spillMetrics() {
+    return new SpillMetrics(getSpillCount(getPointer()), getSpilledBytes(getPointer()));
+  }
+
   @Override
   public Runtime getRuntime() {
     return runtime;
@@ -90,8 +371,12 @@ class DefaultSessionContext extends AbstractProxy implements SessionContext {
   private final TokioRuntime runtime;
 
   DefaultSessionContext(long pointer) {
+    this(pointer, TokioRuntime.create());
+  }
+
+  DefaultSessionContext(long pointer, TokioRuntime runtime) {
     super(pointer);
-    this.runtime = TokioRuntime.create();
+    this.runtime = runtime;
     registerChild(runtime);
   }
 
@@ -0,0 +1,71 @@
+package org.apache.arrow.datafusion;
+
+/** The kind of error reported by the native library, matching the DataFusion error variants */
+public enum ErrorKind {
+  /** The query could not be planned, for example because it references an unknown column */
+  PLAN(0),
+  /** The SQL text could not be parsed */
+  SQL(1),
+  /** The query is not valid for the schema of the data */
+  SCHEMA(2),
+  /** The query failed during execution */
+  EXECUTION(3),
+  /** The query could not acquire enough memory or other resources to run */
+  RESOURCES_EXHAUSTED(4),
+  /** An I/O operation failed */
+  IO(5),
+  /** An Arrow operation failed */
+  ARROW(6),
+  /** The query uses a feature that is not implemented */
+  NOT_IMPLEMENTED(7),
+  /** An object store operation failed */
+  OBJECT_STORE(8),
+  /** Reading or writing Parquet data failed */
+  PARQUET(9),
+  /** Reading Avro data failed */
+  AVRO(10),
+  /** An error raised outside of DataFusion, for example by a Java callback */
+  EXTERNAL(11),
+  /** An unexpected internal error, which indicates a bug */
+  INTERNAL(12);
+
+  private final int id;
+
+  ErrorKind(int id) {
+    this.id = id;
+  }
+
+  /**
+   * Get the identifier used by the native library for this error kind
+   *
+   * @return the error kind identifier
+   */
+  int getId() {
+    return id;
+  }
+
+  /**
+   * Get the error kind with an identifier from the native library
+   *
+   * @param id the error kind identifier
+   * @return the matching error kind, or {@link #EXTERNAL} if the identifier is unknown
+   */
+  static ErrorKind fromId(int id) {
+    for (ErrorKind kind : values()) {
+      if (kind.id == id) {
+        return kind;
+      }
+    }
+    return EXTERNAL;
+  }
+
+  /**
+   * Whether errors of this kind are caused by a mistake in the query rather than a failure while
+   * running it
+   *
+   * @return true for query errors
+   */
+  public boolean isQueryError() {
+    return this == PLAN || this == SQL || this == SCHEMA || this == NOT_IMPLEMENTED;
+  }
+}
@@ -0,0 +1,657 @@
+package org.apache.arrow.datafusion;
+
+import java.math.BigDecimal;
+import java.time.Instant;
+import java.time.LocalDate;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+import java.util.function.Consumer;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.apache.arrow.vector.types.pojo.Field;
+import org.apache.arrow.vector.types.pojo.Schema;
+
+/**
+ * A logical expression that can be used to build {@link DataFrame} operations. Expressions are
+ * immutable, and combining expressions creates a new expression without modifying its inputs.
+ */
+public class Expr extends AbstractProxy implements AutoCloseable {
+
+  private Expr(long pointer) {
+    super(pointer);
+  }
+
+  /**
+   * Create an expression that references a column by name. The name may be qualified with a table
+   * name, for example "t.x".
+   *
+   * @param name the column name
+   * @return the column expression
+   */
+  public static Expr col(String name) {
+    return new Expr(column(name));
+  }
+
+  /**
+   * Create a null literal expression
+   *
+   * @return the literal expression
+   */
+  public static Expr litNull() {
+    return new Expr(nullLiteral());
+  }
+
+  /**
+   * Create a boolean literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(boolean value) {
+    return new Expr(litBoolean(value));
+  }
+
+  /**
+   * Create an 8-bit integer literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(byte value) {
+    return new Expr(litInt8(value));
+  }
+
+  /**
+   * Create a 16-bit integer literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(short value) {
+    return new Expr(litInt16(value));
+  }
+
+  /**
+   * Create a 32-bit integer literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(int value) {
+    return new Expr(litInt32(value));
+  }
+
+  /**
+   * Create a 64-bit integer literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(long value) {
+    return new Expr(litInt64(value));
+  }
+
+  /**
+   * Create a 32-bit floating point literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(float value) {
+    return new Expr(litFloat32(value));
+  }
+
+  /**
+   * Create a 64-bit floating point literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(double value) {
+    return new Expr(litFloat64(value));
+  }
+
+  /**
+   * Create a UTF-8 string literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(String value) {
+    return new Expr(litUtf8(value));
+  }
+
+  /**
+   * Create a binary literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(byte[] value) {
+    return new Expr(litBinary(value));
+  }
+
+  /**
+   * Create a 128-bit decimal literal expression, with the precision and scale of the value. A value
+   * with a negative scale, such as {@code 1E+3}, is converted to a scale of zero.
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(BigDecimal value) {
+    BigDecimal decimal = value.scale() < 0 ? value.setScale(0) : value;
+    // The scale of a value less than one can be larger than its number of digits
+    int precision = Math.max(decimal.precision(), decimal.scale());
+    return fromResult(
+        result ->
+            litDecimal128(
+                decimal.unscaledValue().toString(), precision, decimal.scale(), result));
+  }
+
+  /**
+   * Create a date literal expression
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(LocalDate value) {
+    return new Expr(litDate32(Math.toIntExact(value.toEpochDay())));
+  }
+
+  /**
+   * Create a timestamp literal expression with microsecond precision and no time zone
+   *
+   * @param value the literal value
+   * @return the literal expression
+   */
+  public static Expr lit(Instant value) {
+    return lit(value, null);
+  }
+
+  /**
+   * Create a timestamp literal expression with microsecond precision
+   *
+   * @param value the literal value
+   * @param timezone the time zone of the timestamp, or null for no time zone
+   * @return the literal expression
+   */
+  public static Expr lit(Instant value, String timezone) {
+    long micros =
+        Math.addExact(
+            Math.multiplyExact(value.getEpochSecond(), 1_000_000L), value.getNano() / 1_000L);
+    return new Expr(litTimestampMicrosecond(micros, timezone));
+  }
+
+  /**
+   * Create an expression that calls a built-in scalar function, for example "abs" or "upper"
+   *
+   * @param name the function name
+   * @param args the function arguments
+   * @return the function call expression
+   */
+  public static Expr function(String name, Expr... args) {
+    long[] argPointers = pointers(Arrays.asList(args));
+    return fromResult(result -> scalarFunction(name, argPointers, result));
+  }
+
+  /**
+   * Start building a CASE expression of the form {@code CASE WHEN condition THEN result ... END}
+   *
+   * @param when the first condition
+   * @param then the result when the first condition is true
+   * @return a builder for the CASE expression
+   */
+  public static CaseBuilder when(Expr when, Expr then) {
+    return new CaseBuilder(null).when(when, then);
+  }
+
+  /**
+   * Start building a CASE expression of the form {@code CASE expr WHEN value THEN result ... END}
+   *
+   * @param expr the expression compared against each value
+   * @return a builder for the CASE expression
+   */
+  public static CaseBuilder caseOf(Expr expr) {
+    return new CaseBuilder(expr);
+  }
+
+  /**
+   * Create an expression that is true when this expression equals another
+   *
+   * @param other the expression to compare with
+   * @return the comparison expression
+   */
+  public Expr eq(Expr other) {
+    return new Expr(eq(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when this expression does not equal another
+   *
+   * @param other the expression to compare with
+   * @return the comparison expression
+   */
+  public Expr notEq(Expr other) {
+    return new Expr(notEq(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when this expression is less than another
+   *
+   * @param other the expression to compare with
+   * @return the comparison expression
+   */
+  public Expr lt(Expr other) {
+    return new Expr(lt(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when this expression is less than or equal to another
+   *
+   * @param other the expression to compare with
+   * @return the comparison expression
+   */
+  public Expr ltEq(Expr other) {
+    return new Expr(ltEq(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when this expression is greater than another
+   *
+   * @param other the expression to compare with
+   * @return the comparison expression
+   */
+  public Expr gt(Expr other) {
+    return new Expr(gt(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when this expression is greater than or equal to another
+   *
+   * @param other the expression to compare with
+   * @return the comparison expression
+   */
+  public Expr gtEq(Expr other) {
+    return new Expr(gtEq(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that adds another expression to this expression
+   *
+   * @param other the expression to add
+   * @return the arithmetic expression
+   */
+  public Expr plus(Expr other) {
+    return new Expr(plus(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that subtracts another expression from this expression
+   *
+   * @param other the expression to subtract
+   * @return the arithmetic expression
+   */
+  public Expr minus(Expr other) {
+    return new Expr(minus(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that multiplies this expression by another
+   *
+   * @param other the expression to multiply by
+   * @return the arithmetic expression
+   */
+  public Expr multiply(Expr other) {
+    return new Expr(multiply(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that divides this expression by another
+   *
+   * @param other the expression to divide by
+   * @return the arithmetic expression
+   */
+  public Expr divide(Expr other) {
+    return new Expr(divide(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that computes the remainder of dividing this expression by another
+   *
+   * @param other the expression to divide by
+   * @return the arithmetic expression
+   */
+  public Expr modulo(Expr other) {
+    return new Expr(modulo(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when both this expression and another are true
+   *
+   * @param other the other boolean expression
+   * @return the logical expression
+   */
+  public Expr and(Expr other) {
+    return new Expr(and(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when either this expression or another is true
+   *
+   * @param other the other boolean expression
+   * @return the logical expression
+   */
+  public Expr or(Expr other) {
+    return new Expr(or(getPointer(), other.getPointer()));
+  }
+
+  /**
+   * Create an expression that negates this boolean expression
+   *
+   * @return the logical expression
+   */
+  public Expr not() {
+    return new Expr(not(getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when this expression is null
+   *
+   * @return the null check expression
+   */
+  public Expr isNull() {
+    return new Expr(isNull(getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when this expression is not null
+   *
+   * @return the null check expression
+   */
+  public Expr isNotNull() {
+    return new Expr(isNotNull(getPointer()));
+  }
+
+  /**
+   * Create an expression that is true when this expression matches a SQL LIKE pattern
+   *
+   * @param pattern the pattern to match
+   * @return the pattern matching expression
+   */
+  public Expr like(Expr pattern) {
+    return new Expr(like(getPointer(), pattern.getPointer(), false));
+  }
+
+  /**
+   * Create an expression that is true when this expression does not match a SQL LIKE pattern
+   *
+   * @param pattern the pattern to match
+   * @return the pattern matching expression
+   */
+  public Expr notLike(Expr pattern) {
+    return new Expr(like(getPointer(), pattern.getPointer(), true));
+  }
+
+  /**
+   * Create an expression that is true when this expression equals any expression in a list
+   *
+   * @param list the expressions to compare with
+   * @return the IN list expression
+   */
+  public Expr inList(List<Expr> list) {
+    return new Expr(inList(getPointer(), pointers(list), false));
+  }
+
+  /**
+   * Create an expression that is true when this expression does not equal any expression in a
+   * list
+   *
+   * @param list the expressions to compare with
+   * @return the NOT IN list expression
+   */
+  public Expr notInList(List<Expr> list) {
+    return new Expr(inList(getPointer(), pointers(list), true));
+  }
+
+  /**
+   * Create an expression that casts this expression to another type
+   *
+   * @param type the type to cast to
+   * @return the cast expression
+   */
+  public Expr cast(ArrowType type) {
+    Schema schema = new Schema(Collections.singletonList(Field.nullable("", type)));
+    return fromResult(
+        result ->
+            ArrowSchemas.withExportedSchema(
+                schema,
+                address -> {
+                  cast(getPointer(), address, result);
+                  return null;
+                }));
+  }
+
+  /**
+   * Give this expression an alias, which will be used as the output column name
+   *
+   * @param name the alias
+   * @return the aliased expression
+   */
+  public Expr alias(String name) {
+    return new Expr(alias(getPointer(), name));
+  }
+
+  /**
+   * Create a sort expression from this expression, for use with {@link DataFrame#sort}
+   *
+   * @param ascending whether to sort in ascending order
+   * @param nullsFirst whether null values are sorted before non-null values
+   * @return the sort expression
+   */
+  public Expr sort(boolean ascending, boolean nullsFirst) {
+    return new Expr(sort(getPointer(), ascending, nullsFirst));
+  }
+
+  /**
+   * Create an aggregate expression that counts the non-null values of an expression
+   *
+   * @param expr the expression to aggregate
+   * @return the aggregate expression
+   */
+  public static Expr count(Expr expr) {
+    return new Expr(count(expr.getPointer()));
+  }
+
+  /**
+   * Create an aggregate expression that counts the distinct non-null values of an expression
+   *
+   * @param expr the expression to aggregate
+   * @return the aggregate expression
+   */
+  public static Expr countDistinct(Expr expr) {
+    return new Expr(countDistinct(expr.getPointer()));
+  }
+
+  /**
+   * Create an aggregate expression that sums the values of an expression
+   *
+   * @param expr the expression to aggregate
+   * @return the aggregate expression
+   */
+  public static Expr sum(Expr expr) {
+    return new Expr(sum(expr.getPointer()));
+  }
+
+  /**
+   * Create an aggregate expression that averages the values of an expression
+   *
+   * @param expr the expression to aggregate
+   * @return the aggregate expression
+   */
+  public static Expr avg(Expr expr) {
+    return new Expr(avg(expr.getPointer()));
+  }
+
+  /**
+   * Create an aggregate expression that finds the minimum value of an expression
+   *
+   * @param expr the expression to aggregate
+   * @return the aggregate expression
+   */
+  public static Expr min(Expr expr) {
+    return new Expr(min(expr.getPointer()));
+  }
+
+  /**
+   * Create an aggregate expression that finds the maximum value of an expression
+   *
+   * @param expr the expression to aggregate
+   * @return the aggregate expression
+   */
+  public static Expr max(Expr expr) {
+    return new Expr(max(expr.getPointer()));
+  }
+
+  @Override
+  public String toString() {
+    return display(getPointer());
+  }
+
+  /**
+   * Wrap an expression pointer created by the native library, taking ownership of the expression
+   *
+   * @param pointer the native expression pointer
+   * @return the expression
+   */
+  static Expr fromPointer(long pointer) {
+    return new Expr(pointer);
+  }
+
+  /**
+   * Get the native pointers of a list of expressions
+   *
+   * @param exprs the expressions
+   * @return array of native expression pointers
+   */
+  static long[] pointers(List<Expr> exprs) {
+    return exprs.stream().mapToLong(Expr::getPointer).toArray();
+  }
+
+  static Expr caseWhen(Expr base, List<Expr> whenExprs, List<Expr> thenExprs, Expr elseExpr) {
+    return fromResult(
+        result ->
+            caseWhen(
+                base == null ? 0 : base.getPointer(),
+                pointers(whenExprs),
+                pointers(thenExprs),
+                elseExpr == null ? 0 : elseExpr.getPointer(),
+                result));
+  }
+
+  private static Expr fromResult(Consumer<ObjectResult> nativeMethod) {
+    ObjectResult result = new ObjectResult();
+    nativeMethod.accept(result);
+    return new Expr(result.getObjectId());
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native void destroy(long pointer);
+
+  private static native String display(long pointer);
+
+  private static native long column(String name);
+
+  private static native long alias(long pointer, String name);
+
+  private static native long sort(long pointer, boolean ascending, boolean nullsFirst);
+
+  private static native long count(long pointer);
+
+  private static native long countDistinct(long pointer);
+
+  private static native long sum(long pointer);
+
+  private static native long avg(long pointer);
+
+  private static native long min(long pointer);
+
+  private static native long max(long pointer);
+
+  // Literal native methods
+
+  private static native long nullLiteral();
+
+  private static native long litBoolean(boolean value);
+
+  private static native long litInt8(byte value);
+
+  private static native long litInt16(short value);
+
+  private static native long litInt32(int value);
+
+  private static native long litInt64(long value);
+
+  private static native long litFloat32(float value);
+
+  private static native long litFloat64(double value);
+
+  private static native long litUtf8(String value);
+
+  private static native long litBinary(byte[] value);
+
+  private static native void litDecimal128(
+      String unscaledValue, int precision, int scale, ObjectResult result);
+
+  private static native long litDate32(int value);
+
+  private static native long litTimestampMicrosecond(long value, String timezone);
+
+  // Operator native methods
+
+  private static native long eq(long left, long right);
+
+  private static native long notEq(long left, long right);
+
+  private static native long lt(long left, long right);
+
+  private static native long ltEq(long left, long right);
+
+  private static native long gt(long left, long right);
+
+  private static native long gtEq(long left, long right);
+
+  private static native long plus(long left, long right);
+
+  private static native long minus(long left, long right);
+
+  private static native long multiply(long left, long right);
+
+  private static native long divide(long left, long right);
+
+  private static native long modulo(long left, long right);
+
+  private static native long and(long left, long right);
+
+  private static native long or(long left, long right);
+
+  private static native long not(long pointer);
+
+  private static native long isNull(long pointer);
+
+  private static native long isNotNull(long pointer);
+
+  private static native long like(long pointer, long pattern, boolean negated);
+
+  private static native long inList(long pointer, long[] list, boolean negated);
+
+  private static native void cast(long pointer, long dataTypeSchema, ObjectResult result);
+
+  private static native void caseWhen(
+      long base, long[] whenExprs, long[] thenExprs, long elseExpr, ObjectResult result);
+
+  private static native void scalarFunction(String name, long[] args, ObjectResult result);
+}
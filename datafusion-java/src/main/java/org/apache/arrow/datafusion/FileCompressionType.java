@@ -0,0 +1,30 @@
+package org.apache.arrow.datafusion;
+
+/** The type of compression used by data files */
+public enum FileCompressionType {
+  /** Data files are not compressed */
+  UNCOMPRESSED(0),
+  /** Data files are compressed with gzip */
+  GZIP(1),
+  /** Data files are compressed with bzip2 */
+  BZIP2(2),
+  /** Data files are compressed with xz */
+  XZ(3),
+  /** Data files are compressed with zstd */
+  ZSTD(4);
+
+  private final int id;
+
+  FileCompressionType(int id) {
+    this.id = id;
+  }
+
+  /**
+   * Get the identifier used to pass this compression type to the native library
+   *
+   * @return the compression type identifier
+   */
+  int getId() {
+    return id;
+  }
+}
@@ -0,0 +1,26 @@
+package org.apache.arrow.datafusion;
+
+/** How a {@link CustomTableProvider} handles a filter that is pushed down into a table scan */
+public enum FilterPushDown {
+  /** The filter is not used by the scan, and is applied to the scan results */
+  UNSUPPORTED(0),
+  /** The filter is used by the scan to skip some data, and is also applied to the scan results */
+  INEXACT(1),
+  /** The scan only returns rows matching the filter, so the filter does not need to be reapplied */
+  EXACT(2);
+
+  private final int id;
+
+  FilterPushDown(int id) {
+    this.id = id;
+  }
+
+  /**
+   * Get the identifier used to pass this filter push down type to the native library
+   *
+   * @return the filter push down identifier
+   */
+  int getId() {
+    return id;
+  }
+}
@@ -0,0 +1,36 @@
+package org.apache.arrow.datafusion;
+
+/** The type of join used to combine two {@link DataFrame}s */
+public enum JoinType {
+  /** Rows with matching keys in both DataFrames */
+  INNER(0),
+  /** All rows of the left DataFrame, with nulls for unmatched right columns */
+  LEFT(1),
+  /** All rows of the right DataFrame, with nulls for unmatched left columns */
+  RIGHT(2),
+  /** All rows of both DataFrames, with nulls for unmatched columns */
+  FULL(3),
+  /** Rows of the left DataFrame that have a match in the right DataFrame */
+  LEFT_SEMI(4),
+  /** Rows of the right DataFrame that have a match in the left DataFrame */
+  RIGHT_SEMI(5),
+  /** Rows of the left DataFrame that have no match in the right DataFrame */
+  LEFT_ANTI(6),
+  /** Rows of the right DataFrame that have no match in the left DataFrame */
+  RIGHT_ANTI(7);
+
+  private final int id;
+
+  JoinType(int id) {
+    this.id = id;
+  }
+
+  /**
+   * Get the identifier used to pass this join type to the native library
+   *
+   * @return the join type identifier
+   */
+  int getId() {
+    return id;
+  }
+}
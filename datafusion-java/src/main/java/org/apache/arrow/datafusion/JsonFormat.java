@@ -0,0 +1,18 @@
+package org.apache.arrow.datafusion;
+
+/** The newline-delimited JSON file format configuration */
+public class JsonFormat extends AbstractProxy implements FileFormat {
+  /** Create new JSON format with default options */
+  public JsonFormat() {
+    super(create());
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native long create();
+
+  private static native void destroy(long pointer);
+}
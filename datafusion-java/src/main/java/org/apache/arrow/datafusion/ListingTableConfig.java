@@ -107,20 +107,21 @@ public class ListingTableConfig extends AbstractProxy implements AutoCloseable {
 
   private static CompletableFuture<Long> createListingTableConfig(
       Builder builder, SessionContext context) {
-    CompletableFuture<Long> future = new CompletableFuture<>();
+    NativeFuture<Long> future = new NativeFuture<>();
     Runtime runtime = context.getRuntime();
-    create(
-        runtime.getPointer(),
-        context.getPointer(),
-        builder.tablePaths,
-        builder.options == null ? 0 : builder.options.getPointer(),
-        (errMessage, configId) -> {
-          if (null != errMessage && !errMessage.equals("")) {
-            future.completeExceptionally(new RuntimeException(errMessage));
-          } else {
-            future.complete(configId);
-          }
-        });
+    future.setTask(
+        create(
+            runtime.getPointer(),
+            context.getPointer(),
+            builder.tablePaths,
+            builder.options == null ? 0 : builder.options.getPointer(),
+            (error, configId) -> {
+              if (error != null) {
+                future.completeExceptionally(error);
+              } else {
+                future.complete(configId);
+              }
+            }));
     return future;
   }
 
@@ -129,7 +130,7 @@ public class ListingTableConfig extends AbstractProxy implements AutoCloseable {
     destroy(pointer);
   }
 
-  private static native void create(
+  private static native long create(
       long runtime, long context, String[] tablePaths, long options, ObjectResultCallback callback);
 
   private static native void destroy(long pointer);
@@ -0,0 +1,77 @@
+package org.apache.arrow.datafusion;
+
+import java.util.ArrayList;
+import java.util.List;
+import org.apache.arrow.c.ArrowArray;
+import org.apache.arrow.c.ArrowSchema;
+import org.apache.arrow.c.Data;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.vector.VectorSchemaRoot;
+import org.apache.arrow.vector.types.pojo.Schema;
+
+/**
+ * An in-memory table holding record batches that were created in Java. The data is shared with
+ * the native library without being copied, so the allocator used to create the table and the
+ * vectors must not be closed before this table and any queries that use it are closed.
+ */
+public class MemTable extends AbstractProxy implements TableProvider {
+  /**
+   * Create a new in-memory table
+   *
+   * @param allocator the allocator used to export the record batches
+   * @param schema the schema of the table
+   * @param batches the record batches containing the table data, which must all have a schema
+   *     compatible with the table schema
+   */
+  public MemTable(BufferAllocator allocator, Schema schema, List<VectorSchemaRoot> batches) {
+    super(createMemTable(allocator, schema, batches));
+  }
+
+  private static long createMemTable(
+      BufferAllocator allocator, Schema schema, List<VectorSchemaRoot> batches) {
+    List<ArrowArray> arrowArrays = new ArrayList<>(batches.size());
+    List<ArrowSchema> arrowSchemas = new ArrayList<>(batches.size());
+    try {
+      long[] arrayAddresses = new long[batches.size()];
+      long[] schemaAddresses = new long[batches.size()];
+      for (int i = 0; i < batches.size(); ++i) {
+        ArrowArray arrowArray = ArrowArray.allocateNew(allocator);
+        arrowArrays.add(arrowArray);
+        ArrowSchema arrowSchema = ArrowSchema.allocateNew(allocator);
+        arrowSchemas.add(arrowSchema);
+        Data.exportVectorSchemaRoot(allocator, batches.get(i), null, arrowArray, arrowSchema);
+        arrayAddresses[i] = arrowArray.memoryAddress();
+        schemaAddresses[i] = arrowSchema.memoryAddress();
+      }
+      ObjectResult result = new ObjectResult();
+      ArrowSchemas.withExportedSchema(
+          schema,
+          address -> {
+            create(address, arrayAddresses, schemaAddresses, result);
+            return null;
+          });
+      return result.getObjectId();
+    } finally {
+      // Arrays imported by the native library are left in a released state,
+      // so releasing here only frees arrays that were not imported.
+      for (ArrowArray arrowArray : arrowArrays) {
+        arrowArray.release();
+        arrowArray.close();
+      }
+      for (ArrowSchema arrowSchema : arrowSchemas) {
+        arrowSchema.release();
+        arrowSchema.close();
+      }
+    }
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native void create(
+      long schema, long[] arrays, long[] arraySchemas, ObjectResult result);
+
+  private static native void destroy(long pointer);
+}
@@ -0,0 +1,70 @@
+package org.apache.arrow.datafusion;
+
+import java.util.concurrent.CompletableFuture;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+
+/**
+ * A future that is completed by an asynchronous native task. Cancelling the future cancels the
+ * native task, which stops any work in progress and releases the resources it holds.
+ *
+ * <p>Futures derived from this one, for example with {@link #thenApply}, do not cancel the native
+ * task when they are cancelled.
+ *
+ * @param <T> the result type
+ */
+final class NativeFuture<T> extends CompletableFuture<T> {
+  private static final Logger logger = LoggerFactory.getLogger(NativeFuture.class);
+
+  private final Object lock = new Object();
+  private long task = 0;
+
+  /**
+   * Set the handle of the native task that completes this future. If the future has already been
+   * cancelled, the task is cancelled immediately.
+   *
+   * @param task the task handle, or zero if no task was started
+   */
+  void setTask(long task) {
+    synchronized (lock) {
+      this.task = task;
+      if (task != 0 && isCancelled()) {
+        cancelTask(task);
+      }
+    }
+  }
+
+  @Override
+  public boolean cancel(boolean mayInterruptIfRunning) {
+    boolean cancelled = super.cancel(mayInterruptIfRunning);
+    if (cancelled) {
+      synchronized (lock) {
+        if (task != 0) {
+          cancelTask(task);
+        }
+      }
+    }
+    return cancelled;
+  }
+
+  /**
+   * Complete a future with a new native object. If the future was cancelled before the object was
+   * created, nothing else can use the object so it is closed.
+   *
+   * @param future the future to complete
+   * @param value the new object
+   * @param <T> the type of object
+   */
+  static <T extends AutoCloseable> void completeOrClose(
+      CompletableFuture<? super T> future, T value) {
+    if (!future.complete(value)) {
+      try {
+        value.close();
+      } catch (Exception e) {
+        logger.warn("Failed to close result of cancelled future", e);
+      }
+    }
+  }
+
+  private static native void cancelTask(long task);
+}
@@ -7,7 +7,8 @@ package org.apache.arrow.datafusion;
 interface NativeProxy {
 
   /**
-   * Get a pointer to the native object
+   * Get a pointer to the native object. This is a checked handle rather than a memory address, so
+   * using it after the object is closed results in a {@link DataFusionException}.
    *
    * @return Pointer value as a long
    */
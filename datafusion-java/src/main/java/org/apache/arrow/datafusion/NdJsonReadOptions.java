@@ -0,0 +1,62 @@
+package org.apache.arrow.datafusion;
+
+/** Options for reading newline-delimited JSON data into a table or {@link DataFrame} */
+@SuppressWarnings("UnusedReturnValue")
+public class NdJsonReadOptions extends AbstractProxy implements AutoCloseable {
+  /** Create new JSON read options with default values */
+  public NdJsonReadOptions() {
+    super(create());
+  }
+
+  /**
+   * Set the maximum number of records to read when inferring the schema
+   *
+   * @param maxRecords the maximum number of records
+   * @return the modified {@link NdJsonReadOptions} instance
+   */
+  public NdJsonReadOptions withSchemaInferMaxRecords(long maxRecords) {
+    if (maxRecords < 0) {
+      throw new IllegalArgumentException("maxRecords cannot be negative");
+    }
+    setSchemaInferMaxRecords(getPointer(), maxRecords);
+    return this;
+  }
+
+  /**
+   * Set the file extension used to filter files when reading a directory. Defaults to ".json"
+   *
+   * @param fileExtension the file extension to filter on
+   * @return the modified {@link NdJsonReadOptions} instance
+   */
+  public NdJsonReadOptions withFileExtension(String fileExtension) {
+    setFileExtension(getPointer(), fileExtension);
+    return this;
+  }
+
+  /**
+   * Set the type of compression used by the JSON files
+   *
+   * @param compressionType the file compression type
+   * @return the modified {@link NdJsonReadOptions} instance
+   */
+  public NdJsonReadOptions withFileCompressionType(FileCompressionType compressionType) {
+    checkError(setFileCompressionType(getPointer(), compressionType.getId()));
+    return this;
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native long create();
+
+  private static native void destroy(long pointer);
+
+  private static native void setSchemaInferMaxRecords(long pointer, long maxRecords);
+
+  private static native void setFileExtension(long pointer, String fileExtension);
+
+  private static native DataFusionException setFileCompressionType(
+      long pointer, int compressionTypeId);
+}
@@ -3,17 +3,17 @@ package org.apache.arrow.datafusion;
 /** Helper class for receiving results from the native library that might fail */
 final class ObjectResult {
   private long objectId;
-  private String errorMessage = null;
+  private DataFusionException error = null;
 
-  public long getObjectId() throws RuntimeException {
-    if (errorMessage != null) {
-      throw new RuntimeException(errorMessage);
+  public long getObjectId() throws DataFusionException {
+    if (error != null) {
+      throw error;
     }
     return objectId;
   }
 
-  public void setError(String errorMessage) {
-    this.errorMessage = errorMessage;
+  public void setError(DataFusionException error) {
+    this.error = error;
   }
 
   public void setOk(long objectId) {
@@ -1,5 +1,5 @@
 package org.apache.arrow.datafusion;
 
 interface ObjectResultCallback {
-  void callback(String errMessage, long value);
+  void callback(DataFusionException error, long value);
 }
@@ -0,0 +1,82 @@
+package org.apache.arrow.datafusion;
+
+import java.util.List;
+import org.apache.arrow.vector.types.pojo.Field;
+import org.apache.arrow.vector.types.pojo.Schema;
+
+/** Options for reading Parquet data into a table or {@link DataFrame} */
+@SuppressWarnings("UnusedReturnValue")
+public class ParquetReadOptions extends AbstractProxy implements AutoCloseable {
+  /** Create new Parquet read options with default values */
+  public ParquetReadOptions() {
+    super(create());
+  }
+
+  /**
+   * Set the file extension used to filter files when reading a directory. Defaults to ".parquet"
+   *
+   * @param fileExtension the file extension to filter on
+   * @return the modified {@link ParquetReadOptions} instance
+   */
+  public ParquetReadOptions withFileExtension(String fileExtension) {
+    setFileExtension(getPointer(), fileExtension);
+    return this;
+  }
+
+  /**
+   * Set the partition columns that are encoded in the file paths of Hive-style partitioned data,
+   * for example "/data/year=2023/month=06/part-0.parquet"
+   *
+   * @param partitionCols the names and types of the partition columns
+   * @return the modified {@link ParquetReadOptions} instance
+   */
+  public ParquetReadOptions withTablePartitionCols(List<Field> partitionCols) {
+    Schema partitionSchema = new Schema(partitionCols);
+    checkError(
+        ArrowSchemas.withExportedSchema(
+            partitionSchema, address -> setTablePartitionCols(getPointer(), address)));
+    return this;
+  }
+
+  /**
+   * Set whether pruning is enabled, meaning reading row groups will be skipped based on metadata.
+   * This overrides the session level {@link ParquetOptions#withPruning(boolean)} setting.
+   *
+   * @param enabled whether to enable pruning
+   * @return the modified {@link ParquetReadOptions} instance
+   */
+  public ParquetReadOptions withParquetPruning(boolean enabled) {
+    setParquetPruning(getPointer(), enabled);
+    return this;
+  }
+
+  /**
+   * Set whether file metadata is skipped, to avoid schema conflicts. This overrides the session
+   * level {@link ParquetOptions#withSkipMetadata(boolean)} setting.
+   *
+   * @param enabled whether to skip metadata
+   * @return the modified {@link ParquetReadOptions} instance
+   */
+  public ParquetReadOptions withSkipMetadata(boolean enabled) {
+    setSkipMetadata(getPointer(), enabled);
+    return this;
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native long create();
+
+  private static native void destroy(long pointer);
+
+  private static native void setFileExtension(long pointer, String fileExtension);
+
+  private static native DataFusionException setTablePartitionCols(
+      long pointer, long partitionSchemaAddress);
+
+  private static native void setParquetPruning(long pointer, boolean enabled);
+
+  private static native void setSkipMetadata(long pointer, boolean enabled);
+}
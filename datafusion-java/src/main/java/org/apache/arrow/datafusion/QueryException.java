@@ -0,0 +1,18 @@
+package org.apache.arrow.datafusion;
+
+/**
+ * An error caused by a mistake in the query, such as invalid SQL syntax, a reference to an unknown
+ * table or column, or use of an unsupported feature
+ */
+public class QueryException extends DataFusionException {
+  /**
+   * Create a new exception
+   *
+   * @param kind the kind of error
+   * @param message the error message
+   * @param cause the underlying cause of the error, or null
+   */
+  public QueryException(ErrorKind kind, String message, Throwable cause) {
+    super(kind, message, cause);
+  }
+}
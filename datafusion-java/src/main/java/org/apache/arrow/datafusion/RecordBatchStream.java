@@ -22,4 +22,11 @@ public interface RecordBatchStream extends AutoCloseable, NativeProxy, Dictionar
    *     stream has been reached
    */
   CompletableFuture<Boolean> loadNextBatch();
+
+  /**
+   * Cancel the stream, stopping any batch that is being loaded and releasing the resources used by
+   * the query. Loading further batches fails with a {@link DataFusionException}. The stream must
+   * still be closed.
+   */
+  void cancel();
 }
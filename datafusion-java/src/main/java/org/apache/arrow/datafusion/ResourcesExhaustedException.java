@@ -0,0 +1,14 @@
+package org.apache.arrow.datafusion;
+
+/** An error caused by a query requiring more memory or other resources than are available */
+public class ResourcesExhaustedException extends DataFusionException {
+  /**
+   * Create a new exception
+   *
+   * @param message the error message
+   * @param cause the underlying cause of the error, or null
+   */
+  public ResourcesExhaustedException(String message, Throwable cause) {
+    super(ErrorKind.RESOURCES_EXHAUSTED, message, cause);
+  }
+}
@@ -0,0 +1,126 @@
+package org.apache.arrow.datafusion;
+
+import java.time.Duration;
+
+/**
+ * Configures the Tokio runtime that runs native tasks for a {@link SessionContext}, which is
+ * passed to {@link SessionContexts#create(RuntimeBuilder)} or {@link SessionContexts#withConfig}.
+ * Options that are not set use the Tokio defaults.
+ */
+@SuppressWarnings("UnusedReturnValue")
+public class RuntimeBuilder {
+  private boolean currentThread = false;
+  private int workerThreads = 0;
+  private int maxBlockingThreads = 0;
+  private String threadNamePrefix = null;
+  private long threadStackSize = 0;
+  private Duration threadKeepAlive = null;
+
+  /** Create a new builder for a multi-threaded runtime */
+  public RuntimeBuilder() {}
+
+  /**
+   * Run all native tasks on a single thread rather than a pool of worker threads. Blocking
+   * operations still run on the blocking thread pool.
+   *
+   * @return this builder
+   */
+  public RuntimeBuilder withCurrentThread() {
+    this.currentThread = true;
+    return this;
+  }
+
+  /**
+   * Set the number of worker threads of a multi-threaded runtime. Defaults to the number of CPU
+   * cores.
+   *
+   * @param workerThreads the number of worker threads
+   * @return this builder
+   */
+  public RuntimeBuilder withWorkerThreads(int workerThreads) {
+    if (workerThreads <= 0) {
+      throw new IllegalArgumentException("workerThreads must be positive");
+    }
+    this.workerThreads = workerThreads;
+    return this;
+  }
+
+  /**
+   * Set the maximum number of threads used for blocking operations such as file I/O. Defaults to
+   * 512.
+   *
+   * @param maxBlockingThreads the maximum number of blocking threads
+   * @return this builder
+   */
+  public RuntimeBuilder withMaxBlockingThreads(int maxBlockingThreads) {
+    if (maxBlockingThreads <= 0) {
+      throw new IllegalArgumentException("maxBlockingThreads must be positive");
+    }
+    this.maxBlockingThreads = maxBlockingThreads;
+    return this;
+  }
+
+  /**
+   * Set the prefix of the names of threads created by the runtime, which are numbered in order of
+   * creation. Defaults to threads named "tokio-runtime-worker".
+   *
+   * @param threadNamePrefix the thread name prefix
+   * @return this builder
+   */
+  public RuntimeBuilder withThreadNamePrefix(String threadNamePrefix) {
+    if (threadNamePrefix == null || threadNamePrefix.isEmpty()) {
+      throw new IllegalArgumentException("threadNamePrefix cannot be empty");
+    }
+    this.threadNamePrefix = threadNamePrefix;
+    return this;
+  }
+
+  /**
+   * Set the stack size of threads created by the runtime. Defaults to 2 MiB.
+   *
+   * @param threadStackSize the stack size in bytes
+   * @return this builder
+   */
+  public RuntimeBuilder withThreadStackSize(long threadStackSize) {
+    if (threadStackSize <= 0) {
+      throw new IllegalArgumentException("threadStackSize must be positive");
+    }
+    this.threadStackSize = threadStackSize;
+    return this;
+  }
+
+  /**
+   * Set how long idle blocking threads are kept before they exit. Defaults to 10 seconds.
+   *
+   * @param threadKeepAlive the keep alive duration
+   * @return this builder
+   */
+  public RuntimeBuilder withThreadKeepAlive(Duration threadKeepAlive) {
+    if (threadKeepAlive == null || threadKeepAlive.isNegative()) {
+      throw new IllegalArgumentException("threadKeepAlive cannot be negative");
+    }
+    this.threadKeepAlive = threadKeepAlive;
+    return this;
+  }
+
+  /**
+   * Build a new runtime from the configured options
+   *
+   * @return the created runtime
+   * @throws DataFusionException if the runtime cannot be created, for example because threads
+   *     cannot be started
+   */
+  TokioRuntime build() {
+    if (currentThread && workerThreads != 0) {
+      throw new IllegalStateException("workerThreads cannot be set for a current thread runtime");
+    }
+    return new TokioRuntime(
+        TokioRuntime.createTokioRuntime(
+            currentThread,
+            workerThreads,
+            maxBlockingThreads,
+            threadNamePrefix,
+            threadStackSize,
+            threadKeepAlive == null ? -1 : threadKeepAlive.toNanos()));
+  }
+}
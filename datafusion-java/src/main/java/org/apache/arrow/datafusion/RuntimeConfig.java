@@ -0,0 +1,124 @@
+package org.apache.arrow.datafusion;
+
+import java.nio.file.Path;
+import java.util.List;
+
+/**
+ * Configuration of the execution environment used to run queries for a {@link SessionContext},
+ * which is passed to {@link SessionContexts#withConfig(SessionConfig, RuntimeConfig)}. Each session
+ * context gets its own memory pool and spill directories, so the limits apply to each context
+ * separately.
+ */
+@SuppressWarnings("UnusedReturnValue")
+public class RuntimeConfig extends AbstractProxy implements AutoCloseable {
+  /**
+   * Create a new {@link RuntimeConfig} with an unbounded memory pool, that spills to the operating
+   * system's temporary directory
+   */
+  public RuntimeConfig() {
+    super(create());
+  }
+
+  /**
+   * Limit the memory used by queries with a pool that grants memory to operators on a first come
+   * first served basis. Queries that exceed the limit fail with a {@link
+   * ResourcesExhaustedException}. This is suited to queries without operators that can spill to
+   * disk.
+   *
+   * @param poolSize the maximum memory in bytes
+   * @return the modified {@link RuntimeConfig} instance
+   */
+  public RuntimeConfig withGreedyMemoryPool(long poolSize) {
+    setGreedyMemoryPool(getPointer(), checkPoolSize(poolSize));
+    return this;
+  }
+
+  /**
+   * Limit the memory used by queries with a pool that shares memory fairly between operators that
+   * can spill to disk, such as sorts. Queries that cannot spill enough data to stay within the
+   * limit fail with a {@link ResourcesExhaustedException}.
+   *
+   * @param poolSize the maximum memory in bytes
+   * @return the modified {@link RuntimeConfig} instance
+   */
+  public RuntimeConfig withFairSpillPool(long poolSize) {
+    setFairSpillPool(getPointer(), checkPoolSize(poolSize));
+    return this;
+  }
+
+  /**
+   * Remove any limit on the memory used by queries
+   *
+   * @return the modified {@link RuntimeConfig} instance
+   */
+  public RuntimeConfig withUnboundedMemoryPool() {
+    setUnboundedMemoryPool(getPointer());
+    return this;
+  }
+
+  /**
+   * Disable spilling to disk, so that queries that exceed the memory limit fail instead
+   *
+   * @return the modified {@link RuntimeConfig} instance
+   */
+  public RuntimeConfig withDiskManagerDisabled() {
+    setDiskManagerDisabled(getPointer());
+    return this;
+  }
+
+  /**
+   * Spill to files in a new temporary directory in the operating system's temporary directory. This
+   * is the default.
+   *
+   * @return the modified {@link RuntimeConfig} instance
+   */
+  public RuntimeConfig withDiskManagerOsTempDir() {
+    setDiskManagerOsTempDir(getPointer());
+    return this;
+  }
+
+  /**
+   * Spill to files in new temporary directories within the given directories. Spill files are
+   * spread across the directories.
+   *
+   * @param directories the directories to spill to, which must already exist
+   * @return the modified {@link RuntimeConfig} instance
+   */
+  public RuntimeConfig withDiskManagerSpillDirectories(List<Path> directories) {
+    if (directories.isEmpty()) {
+      throw new IllegalArgumentException("directories cannot be empty");
+    }
+    String[] paths =
+        directories.stream().map(path -> path.toAbsolutePath().toString()).toArray(String[]::new);
+    setDiskManagerSpillDirectories(getPointer(), paths);
+    return this;
+  }
+
+  private static long checkPoolSize(long poolSize) {
+    if (poolSize < 0) {
+      throw new IllegalArgumentException("poolSize cannot be negative");
+    }
+    return poolSize;
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native long create();
+
+  private static native void destroy(long pointer);
+
+  private static native void setGreedyMemoryPool(long pointer, long poolSize);
+
+  private static native void setFairSpillPool(long pointer, long poolSize);
+
+  private static native void setUnboundedMemoryPool(long pointer);
+
+  private static native void setDiskManagerDisabled(long pointer);
+
+  private static native void setDiskManagerOsTempDir(long pointer);
+
+  private static native void setDiskManagerSpillDirectories(long pointer, String[] directories);
+}
@@ -0,0 +1,25 @@
+package org.apache.arrow.datafusion;
+
+import java.util.List;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.vector.FieldVector;
+
+/**
+ * A scalar user defined function implemented in Java, which can be registered with {@link
+ * SessionContext#registerUdf}. The function may be called from native threads while queries
+ * execute, so implementations must be thread safe.
+ */
+@FunctionalInterface
+public interface ScalarUdf {
+  /**
+   * Evaluate the function for a batch of rows
+   *
+   * @param allocator the allocator to use for the result
+   * @param args the argument values, with one vector per argument. The vectors are closed once
+   *     this method returns.
+   * @return the result values, with the same number of rows as the arguments. The result is closed
+   *     by the caller.
+   * @throws Exception if evaluating the function fails
+   */
+  FieldVector evaluate(BufferAllocator allocator, List<FieldVector> args) throws Exception;
+}
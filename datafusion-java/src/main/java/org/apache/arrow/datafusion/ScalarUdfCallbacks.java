@@ -0,0 +1,36 @@
+package org.apache.arrow.datafusion;
+
+import java.util.List;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.util.AutoCloseables;
+import org.apache.arrow.vector.FieldVector;
+
+/** Methods called from the native library to evaluate a {@link ScalarUdf} */
+final class ScalarUdfCallbacks {
+  private final BufferAllocator allocator;
+  private final ScalarUdf udf;
+
+  ScalarUdfCallbacks(BufferAllocator allocator, ScalarUdf udf) {
+    this.allocator = allocator;
+    this.udf = udf;
+  }
+
+  /**
+   * Evaluate the function for arrays exported by the native library
+   *
+   * @param arrays addresses of the exported argument arrays, which are owned by this method
+   * @param schemas addresses of the exported argument schemas
+   * @param resultArray address of the native array struct to export the result into
+   * @param resultSchema address of the native schema struct to export the result into
+   * @throws Exception if evaluating the function fails
+   */
+  void evaluate(long[] arrays, long[] schemas, long resultArray, long resultSchema)
+      throws Exception {
+    List<FieldVector> args = ArrowVectors.importVectors(allocator, arrays, schemas);
+    try (FieldVector result = udf.evaluate(allocator, args)) {
+      ArrowVectors.exportVector(allocator, result, resultArray, resultSchema);
+    } finally {
+      AutoCloseables.close(args);
+    }
+  }
+}
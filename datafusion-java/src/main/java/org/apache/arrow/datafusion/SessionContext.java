@@ -1,9 +1,19 @@
 package org.apache.arrow.datafusion;
 
 import java.nio.file.Path;
+import java.util.List;
 import java.util.concurrent.CompletableFuture;
+import java.util.function.Supplier;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.vector.types.pojo.ArrowType;
 
-/** A session context holds resources and is the entrance for obtaining {@link DataFrame} */
+/**
+ * A session context holds resources and is the entrance for obtaining {@link DataFrame}
+ *
+ * <p>Methods that return a {@link CompletableFuture} run on the context's Tokio runtime without
+ * blocking the calling thread, and complete the future from a runtime thread. Dependent stages
+ * that are not run asynchronously therefore also run on a runtime thread, and should not block.
+ */
 public interface SessionContext extends AutoCloseable, NativeProxy {
 
   /**
@@ -23,6 +33,16 @@ public interface SessionContext extends AutoCloseable, NativeProxy {
    */
   CompletableFuture<Void> registerCsv(String name, Path path);
 
+  /**
+   * Registering a csv file with the context
+   *
+   * @param name The table name to use to refer to the data
+   * @param path Path to the CSV file
+   * @param options Options for reading the CSV data
+   * @return Future that is completed when the CSV is registered
+   */
+  CompletableFuture<Void> registerCsv(String name, Path path, CsvReadOptions options);
+
   /**
    * Registering a parquet file with the context
    *
@@ -32,6 +52,103 @@ public interface SessionContext extends AutoCloseable, NativeProxy {
    */
   CompletableFuture<Void> registerParquet(String name, Path path);
 
+  /**
+   * Registering a parquet file with the context
+   *
+   * @param name The table name to use to refer to the data
+   * @param path Path to the Parquet file or directory
+   * @param options Options for reading the Parquet data
+   * @return Future that is completed when the Parquet file is registered
+   */
+  CompletableFuture<Void> registerParquet(String name, Path path, ParquetReadOptions options);
+
+  /**
+   * Read a parquet file into a {@link DataFrame}
+   *
+   * @param path Path to the Parquet file
+   * @return DataFrame representing the Parquet data
+   */
+  CompletableFuture<DataFrame> readParquet(Path path);
+
+  /**
+   * Read a parquet file into a {@link DataFrame}
+   *
+   * @param path Path to the Parquet file or directory
+   * @param options Options for reading the Parquet data
+   * @return DataFrame representing the Parquet data
+   */
+  CompletableFuture<DataFrame> readParquet(Path path, ParquetReadOptions options);
+
+  /**
+   * Read a csv file into a {@link DataFrame}
+   *
+   * @param path Path to the CSV file
+   * @return DataFrame representing the CSV data
+   */
+  CompletableFuture<DataFrame> readCsv(Path path);
+
+  /**
+   * Read a csv file into a {@link DataFrame}
+   *
+   * @param path Path to the CSV file
+   * @param options Options for reading the CSV data
+   * @return DataFrame representing the CSV data
+   */
+  CompletableFuture<DataFrame> readCsv(Path path, CsvReadOptions options);
+
+  /**
+   * Registering a newline-delimited JSON file with the context
+   *
+   * @param name The table name to use to refer to the data
+   * @param path Path to the JSON file
+   * @return Future that is completed when the JSON file is registered
+   */
+  CompletableFuture<Void> registerJson(String name, Path path);
+
+  /**
+   * Registering a newline-delimited JSON file with the context
+   *
+   * @param name The table name to use to refer to the data
+   * @param path Path to the JSON file or directory
+   * @param options Options for reading the JSON data
+   * @return Future that is completed when the JSON file is registered
+   */
+  CompletableFuture<Void> registerJson(String name, Path path, NdJsonReadOptions options);
+
+  /**
+   * Read a newline-delimited JSON file into a {@link DataFrame}
+   *
+   * @param path Path to the JSON file
+   * @return DataFrame representing the JSON data
+   */
+  CompletableFuture<DataFrame> readJson(Path path);
+
+  /**
+   * Read a newline-delimited JSON file into a {@link DataFrame}
+   *
+   * @param path Path to the JSON file or directory
+   * @param options Options for reading the JSON data
+   * @return DataFrame representing the JSON data
+   */
+  CompletableFuture<DataFrame> readJson(Path path, NdJsonReadOptions options);
+
+  /**
+   * Registering an Avro file with the context
+   *
+   * @param name The table name to use to refer to the data
+   * @param path Path to the Avro file or directory
+   * @return Future that is completed when the Avro file is registered
+   */
+  CompletableFuture<Void> registerAvro(String name, Path path);
+
+  /**
+   * Read an Avro file into a {@link DataFrame}
+   *
+   * @param path Path to the Avro file
+   * @return DataFrame representing the Avro data
+   */
+  CompletableFuture<DataFrame> readAvro(Path path);
+
   /**
    * Register a table provider with the context
    *
@@ -40,6 +157,69 @@ public interface SessionContext extends AutoCloseable, NativeProxy {
    */
   void registerTable(String name, TableProvider tableProvider);
 
+  /**
+   * Register a scalar user defined function implemented in Java, so that it can be called from SQL
+   *
+   * @param allocator the allocator used to pass data to and from the function, which must not be
+   *     closed before this context is closed
+   * @param name the function name
+   * @param argumentTypes the types of the function arguments
+   * @param returnType the type of the function result
+   * @param volatility how the function result may change between calls
+   * @param udf the function implementation
+   */
+  void registerUdf(
+      BufferAllocator allocator,
+      String name,
+      List<ArrowType> argumentTypes,
+      ArrowType returnType,
+      Volatility volatility,
+      ScalarUdf udf);
+
+  /**
+   * Register an aggregate user defined function implemented in Java, so that it can be called from
+   * SQL
+   *
+   * @param allocator the allocator used to pass data to and from the accumulators, which must not
+   *     be closed before this context is closed
+   * @param name the function name
+   * @param argumentTypes the types of the function arguments
+   * @param returnType the type of the function result
+   * @param stateTypes the types of the intermediate state fields produced by each accumulator
+   * @param volatility how the function result may change between calls
+   * @param accumulatorFactory creates a new accumulator for each group being aggregated
+   */
+  void registerUdaf(
+      BufferAllocator allocator,
+      String name,
+      List<ArrowType> argumentTypes,
+      ArrowType returnType,
+      List<ArrowType> stateTypes,
+      Volatility volatility,
+      Supplier<Accumulator> accumulatorFactory);
+
+  /**
+   * Load a native shared library of user defined functions and register its functions with the
+   * context. The library must export a {@code datafusion_jni_udf_abi} function returning the UDF
+   * ABI it was built for, which is checked before its {@code datafusion_jni_register_udfs} function
+   * is called. The library must be built with the same Rust compiler, DataFusion version and
+   * DataFusion features as the native library used by this package. Only the DataFusion version is
+   * checked, so a library built with a different compiler may crash the JVM. Loaded libraries are
+   * never unloaded.
+   *
+   * @param path Path to the shared library
+   */
+  void loadUdfLibrary(Path path);
+
+  /**
+   * Get the spills to disk by queries run in this context. Spills are counted once a query's
+   * results have been collected, shown or written, or its {@link RecordBatchStream} has been
+   * closed.
+   *
+   * @return the spill metrics of this context
+   */
+  SpillMetrics spillMetrics();
+
   /**
    * Get the runtime associated with this context
    *
@@ -1,6 +1,7 @@
 package org.apache.arrow.datafusion;
 
 import java.util.function.Consumer;
+import java.util.function.LongSupplier;
 
 /** Manages session contexts */
 public class SessionContexts {
@@ -18,9 +19,11 @@ public class SessionContexts {
    * Create a new session context using a SessionConfig
    *
    * @param configPointer pointer to the native session config object to use
+   * @param runtimeConfigPointer pointer to the native runtime config object to use, or zero to use
+   *     the default runtime config
    * @return native pointer to the created session context
    */
-  static native long createSessionContextWithConfig(long configPointer);
+  static native long createSessionContextWithConfig(long configPointer, long runtimeConfigPointer);
 
   /**
    * Destroy a session context
@@ -43,6 +46,17 @@ public class SessionContexts {
     return new DefaultSessionContext(pointer);
   }
 
+  /**
+   * Create a new default session context that runs native tasks on a configured runtime
+   *
+   * @param runtimeBuilder the configuration of the runtime for the session
+   * @return The created context
+   * @throws DataFusionException if the runtime cannot be created
+   */
+  public static SessionContext create(RuntimeBuilder runtimeBuilder) {
+    return withRuntime(runtimeBuilder, SessionContexts::createSessionContext);
+  }
+
   /**
    * Create a new session context using the provided configuration
    *
@@ -50,10 +64,55 @@ public class SessionContexts {
    * @return The created context
    */
   public static SessionContext withConfig(SessionConfig config) {
-    long pointer = createSessionContextWithConfig(config.getPointer());
+    long pointer = createSessionContextWithConfig(config.getPointer(), 0);
     return new DefaultSessionContext(pointer);
   }
 
+  /**
+   * Create a new session context using the provided configuration, that runs native tasks on a
+   * configured runtime
+   *
+   * @param config the configuration for the session
+   * @param runtimeBuilder the configuration of the runtime for the session
+   * @return The created context
+   * @throws DataFusionException if the runtime cannot be created
+   */
+  public static SessionContext withConfig(SessionConfig config, RuntimeBuilder runtimeBuilder) {
+    return withRuntime(
+        runtimeBuilder, () -> createSessionContextWithConfig(config.getPointer(), 0));
+  }
+
+  /**
+   * Create a new session context using the provided configuration and execution environment
+   * configuration
+   *
+   * @param config the configuration for the session
+   * @param runtimeConfig the configuration of the execution environment for the session
+   * @return The created context
+   * @throws DataFusionException if the execution environment cannot be created
+   */
+  public static SessionContext withConfig(SessionConfig config, RuntimeConfig runtimeConfig) {
+    long pointer = createSessionContextWithConfig(config.getPointer(), runtimeConfig.getPointer());
+    return new DefaultSessionContext(pointer);
+  }
+
+  /**
+   * Create a new session context using the provided configuration and execution environment
+   * configuration, that runs native tasks on a configured runtime
+   *
+   * @param config the configuration for the session
+   * @param runtimeConfig the configuration of the execution environment for the session
+   * @param runtimeBuilder the configuration of the runtime for the session
+   * @return The created context
+   * @throws DataFusionException if the execution environment or runtime cannot be created
+   */
+  public static SessionContext withConfig(
+      SessionConfig config, RuntimeConfig runtimeConfig, RuntimeBuilder runtimeBuilder) {
+    return withRuntime(
+        runtimeBuilder,
+        () -> createSessionContextWithConfig(config.getPointer(), runtimeConfig.getPointer()));
+  }
+
   /**
    * Create a new session context using the provided callback to configure the session
    *
@@ -63,8 +122,23 @@ public class SessionContexts {
    */
   public static SessionContext withConfig(Consumer<SessionConfig> configuration) throws Exception {
     try (SessionConfig config = new SessionConfig().withConfiguration(configuration)) {
-      long pointer = createSessionContextWithConfig(config.getPointer());
+      long pointer = createSessionContextWithConfig(config.getPointer(), 0);
       return new DefaultSessionContext(pointer);
     }
   }
+
+  private static SessionContext withRuntime(
+      RuntimeBuilder runtimeBuilder, LongSupplier createContext) {
+    TokioRuntime runtime = runtimeBuilder.build();
+    try {
+      return new DefaultSessionContext(createContext.getAsLong(), runtime);
+    } catch (RuntimeException e) {
+      try {
+        runtime.close();
+      } catch (Exception closeException) {
+        e.addSuppressed(closeException);
+      }
+      throw e;
+    }
+  }
 }
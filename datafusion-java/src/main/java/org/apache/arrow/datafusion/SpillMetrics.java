@@ -0,0 +1,38 @@
+package org.apache.arrow.datafusion;
+
+/**
+ * Spills of intermediate data to disk by queries run in a {@link SessionContext}, accumulated over
+ * all queries that have finished being collected, streamed, shown or written
+ */
+public final class SpillMetrics {
+  private final long spillCount;
+  private final long spilledBytes;
+
+  SpillMetrics(long spillCount, long spilledBytes) {
+    this.spillCount = spillCount;
+    this.spilledBytes = spilledBytes;
+  }
+
+  /**
+   * Get the number of times data was spilled to disk
+   *
+   * @return the number of spills
+   */
+  public long spillCount() {
+    return spillCount;
+  }
+
+  /**
+   * Get the total size of data spilled to disk
+   *
+   * @return the number of bytes spilled
+   */
+  public long spilledBytes() {
+    return spilledBytes;
+  }
+
+  @Override
+  public String toString() {
+    return "SpillMetrics{spillCount=" + spillCount + ", spilledBytes=" + spilledBytes + "}";
+  }
+}
@@ -0,0 +1,43 @@
+package org.apache.arrow.datafusion;
+
+import org.apache.arrow.c.ArrowArrayStream;
+import org.apache.arrow.c.Data;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.vector.ipc.ArrowReader;
+
+/**
+ * A table that streams record batches from an {@link ArrowReader} without loading all data up
+ * front. The reader can only be read once, so querying the table a second time fails. The reader
+ * is closed once the table is closed and no longer used by any query, and the allocator must not
+ * be closed before then.
+ */
+public class StreamTable extends AbstractProxy implements TableProvider {
+  /**
+   * Create a new stream table
+   *
+   * @param allocator the allocator used to export the reader data
+   * @param reader the reader that provides the table data
+   */
+  public StreamTable(BufferAllocator allocator, ArrowReader reader) {
+    super(createStreamTable(allocator, reader));
+  }
+
+  private static long createStreamTable(BufferAllocator allocator, ArrowReader reader) {
+    try (ArrowArrayStream stream = ArrowArrayStream.allocateNew(allocator)) {
+      Data.exportArrayStream(allocator, reader, stream);
+      ObjectResult result = new ObjectResult();
+      // The native library takes ownership of the exported stream
+      create(stream.memoryAddress(), result);
+      return result.getObjectId();
+    }
+  }
+
+  @Override
+  void doClose(long pointer) {
+    destroy(pointer);
+  }
+
+  private static native void create(long stream, ObjectResult result);
+
+  private static native void destroy(long pointer);
+}
@@ -0,0 +1,74 @@
+package org.apache.arrow.datafusion;
+
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Optional;
+import org.apache.arrow.c.ArrowArrayStream;
+import org.apache.arrow.c.ArrowSchema;
+import org.apache.arrow.c.Data;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.vector.ipc.ArrowReader;
+
+/** Methods called from the native library to use a {@link CustomTableProvider} */
+final class TableProviderCallbacks {
+  private final BufferAllocator allocator;
+  private final CustomTableProvider provider;
+
+  TableProviderCallbacks(BufferAllocator allocator, CustomTableProvider provider) {
+    this.allocator = allocator;
+    this.provider = provider;
+  }
+
+  /**
+   * Export the table schema into a native Arrow schema struct
+   *
+   * @param schemaAddress address of the native schema struct to export into
+   */
+  void exportSchema(long schemaAddress) {
+    ArrowSchema arrowSchema = ArrowSchema.wrap(schemaAddress);
+    Data.exportSchema(allocator, provider.schema(), null, arrowSchema);
+  }
+
+  /**
+   * Scan the table and export the data into a native Arrow array stream struct
+   *
+   * @param projection indices of the columns to return, or null for all columns
+   * @param filters native filter expression pointers, which are owned by this method
+   * @param limit maximum number of rows to return, or -1 for no limit
+   * @param streamAddress address of the native stream struct to export into
+   * @throws Exception if the scan fails
+   */
+  void scan(int[] projection, long[] filters, long limit, long streamAddress) throws Exception {
+    List<Expr> filterExprs = new ArrayList<>(filters.length);
+    for (long filter : filters) {
+      filterExprs.add(Expr.fromPointer(filter));
+    }
+    try {
+      ArrowReader reader =
+          provider.scan(
+              allocator,
+              Optional.ofNullable(projection),
+              filterExprs,
+              limit < 0 ? Optional.empty() : Optional.of(limit));
+      ArrowArrayStream stream = ArrowArrayStream.wrap(streamAddress);
+      Data.exportArrayStream(allocator, reader, stream);
+    } finally {
+      for (Expr filter : filterExprs) {
+        filter.close();
+      }
+    }
+  }
+
+  /**
+   * Get how a filter is handled when pushed down into a scan
+   *
+   * @param filter native filter expression pointer, which is owned by this method
+   * @return the filter push down identifier
+   * @throws Exception if closing the filter fails
+   */
+  int supportsFilterPushDown(long filter) throws Exception {
+    try (Expr filterExpr = Expr.fromPointer(filter)) {
+      return provider.supportsFilterPushDown(filterExpr).getId();
+    }
+  }
+}
@@ -12,14 +12,16 @@ final class TokioRuntime extends AbstractProxy implements Runtime {
   }
 
   static TokioRuntime create() {
-    long pointer = TokioRuntime.createTokioRuntime();
-    if (pointer <= 0) {
-      throw new IllegalStateException("failed to create runtime");
-    }
-    return new TokioRuntime(pointer);
+    return new RuntimeBuilder().build();
   }
 
-  static native long createTokioRuntime();
+  static native long createTokioRuntime(
+      boolean currentThread,
+      int workerThreads,
+      int maxBlockingThreads,
+      String threadNamePrefix,
+      long threadStackSize,
+      long threadKeepAliveNanos);
 
   static native void destroyTokioRuntime(long pointer);
 }
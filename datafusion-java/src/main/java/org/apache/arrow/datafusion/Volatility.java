@@ -0,0 +1,26 @@
+package org.apache.arrow.datafusion;
+
+/** How the result of a user defined function may change between calls with the same arguments */
+public enum Volatility {
+  /** The function always returns the same result for the same arguments */
+  IMMUTABLE(0),
+  /** The function returns the same result for the same arguments within a single query */
+  STABLE(1),
+  /** The function may return a different result on every call */
+  VOLATILE(2);
+
+  private final int id;
+
+  Volatility(int id) {
+    this.id = id;
+  }
+
+  /**
+   * Get the identifier used to pass this volatility to the native library
+   *
+   * @return the volatility identifier
+   */
+  int getId() {
+    return id;
+  }
+}
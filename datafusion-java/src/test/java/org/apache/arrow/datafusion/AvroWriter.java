@@ -0,0 +1,30 @@
+package org.apache.arrow.datafusion;
+
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.function.BiConsumer;
+import org.apache.avro.Schema;
+import org.apache.avro.file.DataFileWriter;
+import org.apache.avro.generic.GenericData;
+import org.apache.avro.generic.GenericDatumWriter;
+
+public class AvroWriter {
+  public static void writeAvro(
+      Path path, String schema, int rowCount, BiConsumer<Integer, GenericData.Record> setRecord)
+      throws IOException {
+    Schema.Parser parser = new Schema.Parser().setValidate(true);
+    Schema avroSchema = parser.parse(schema);
+
+    Files.createDirectories(path.getParent());
+    try (DataFileWriter<GenericData.Record> writer =
+        new DataFileWriter<>(new GenericDatumWriter<GenericData.Record>(avroSchema))) {
+      writer.create(avroSchema, path.toFile());
+      for (int i = 0; i < rowCount; ++i) {
+        GenericData.Record record = new GenericData.Record(avroSchema);
+        setRecord.accept(i, record);
+        writer.append(record);
+      }
+    }
+  }
+}
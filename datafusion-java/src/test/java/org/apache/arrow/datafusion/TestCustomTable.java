@@ -0,0 +1,133 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.io.ByteArrayInputStream;
+import java.io.ByteArrayOutputStream;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+import java.util.Optional;
+import java.util.concurrent.CompletionException;
+import java.util.stream.Collectors;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.BigIntVector;
+import org.apache.arrow.vector.VectorSchemaRoot;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.ipc.ArrowStreamReader;
+import org.apache.arrow.vector.ipc.ArrowStreamWriter;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.apache.arrow.vector.types.pojo.Field;
+import org.apache.arrow.vector.types.pojo.Schema;
+import org.junit.jupiter.api.Test;
+
+public class TestCustomTable {
+  private static final Schema SCHEMA =
+      new Schema(
+          Arrays.asList(
+              Field.nullable("id", new ArrowType.Int(64, true)),
+              Field.nullable("value", new ArrowType.Int(64, true))));
+
+  private static final long[][] DATA = {{1, 10}, {2, 20}, {3, 30}};
+
+  /** A table that returns fixed data and records the arguments it is scanned with */
+  private static class FixedTableProvider implements CustomTableProvider {
+    private final List<String> pushedDownFilters = Collections.synchronizedList(new ArrayList<>());
+    private volatile int[] scannedProjection;
+    private volatile List<String> scannedFilters;
+
+    @Override
+    public Schema schema() {
+      return SCHEMA;
+    }
+
+    @Override
+    public ArrowReader scan(
+        BufferAllocator allocator,
+        Optional<int[]> projection,
+        List<Expr> filters,
+        Optional<Long> limit)
+        throws Exception {
+      int[] columns = projection.orElse(new int[] {0, 1});
+      scannedProjection = columns;
+      scannedFilters = filters.stream().map(Expr::toString).collect(Collectors.toList());
+      List<Field> fields = new ArrayList<>();
+      for (int column : columns) {
+        fields.add(SCHEMA.getFields().get(column));
+      }
+      ByteArrayOutputStream output = new ByteArrayOutputStream();
+      try (VectorSchemaRoot root = VectorSchemaRoot.create(new Schema(fields), allocator);
+          ArrowStreamWriter writer = new ArrowStreamWriter(root, null, output)) {
+        writer.start();
+        for (int i = 0; i < columns.length; ++i) {
+          BigIntVector vector = (BigIntVector) root.getVector(i);
+          vector.allocateNew(DATA.length);
+          for (int row = 0; row < DATA.length; ++row) {
+            vector.set(row, DATA[row][columns[i]]);
+          }
+        }
+        root.setRowCount(DATA.length);
+        writer.writeBatch();
+        writer.end();
+      }
+      return new ArrowStreamReader(new ByteArrayInputStream(output.toByteArray()), allocator);
+    }
+
+    @Override
+    public FilterPushDown supportsFilterPushDown(Expr filter) {
+      pushedDownFilters.add(filter.toString());
+      return FilterPushDown.INEXACT;
+    }
+  }
+
+  @Test
+  public void testQueryCustomTable() throws Exception {
+    FixedTableProvider provider = new FixedTableProvider();
+    // The context must be closed before the allocator, as it may hold exported data
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create();
+        CustomTable table = new CustomTable(allocator, provider)) {
+      context.registerTable("t", table);
+      try (DataFrame dataFrame = context.sql("SELECT value FROM t WHERE id > 1").join()) {
+        assertArrayEquals(
+            new long[] {20, 30}, TestDataFrame.collectColumn(dataFrame, allocator, 0));
+      }
+      assertFalse(provider.pushedDownFilters.isEmpty());
+      assertEquals(1, provider.scannedFilters.size());
+      assertTrue(provider.scannedFilters.get(0).contains("id"));
+      assertEquals(
+          Arrays.asList("id", "value"),
+          Arrays.stream(provider.scannedProjection)
+              .mapToObj(column -> SCHEMA.getFields().get(column).getName())
+              .sorted()
+              .collect(Collectors.toList()));
+    }
+  }
+
+  @Test
+  public void testScanError() throws Exception {
+    CustomTableProvider provider =
+        new FixedTableProvider() {
+          @Override
+          public ArrowReader scan(
+              BufferAllocator allocator,
+              Optional<int[]> projection,
+              List<Expr> filters,
+              Optional<Long> limit) {
+            throw new IllegalStateException("scan failed");
+          }
+        };
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create();
+        CustomTable table = new CustomTable(allocator, provider)) {
+      context.registerTable("t", table);
+      try (DataFrame dataFrame = context.sql("SELECT * FROM t").join()) {
+        CompletionException exception =
+            assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+        assertTrue(exception.getMessage().contains("scan failed"));
+      }
+    }
+  }
+}
@@ -0,0 +1,293 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+import java.util.Optional;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.BigIntVector;
+import org.apache.arrow.vector.VectorSchemaRoot;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.apache.arrow.vector.types.pojo.Field;
+import org.apache.arrow.vector.types.pojo.Schema;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+public class TestDataFrame {
+  @Test
+  public void testSelectColumnsSortAndLimit(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = readTestData(context, tempDir);
+        DataFrame selected = dataFrame.selectColumns("y");
+        Expr y = Expr.col("y");
+        Expr ySort = y.sort(false, false);
+        DataFrame sorted = selected.sort(Collections.singletonList(ySort));
+        DataFrame limited = sorted.limit(1, Optional.of(2L))) {
+      assertArrayEquals(new long[] {12, 4}, collectColumn(limited, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testSelectExpressions(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = readTestData(context, tempDir);
+        Expr x = Expr.col("x");
+        Expr alias = x.alias("a");
+        Expr a = Expr.col("a");
+        Expr sort = a.sort(true, false);
+        DataFrame selected = dataFrame.select(Collections.singletonList(alias));
+        DataFrame sorted = selected.sort(Collections.singletonList(sort))) {
+      assertArrayEquals(new long[] {1, 1, 3, 3}, collectColumn(sorted, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testAggregate(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = readTestData(context, tempDir);
+        Expr x = Expr.col("x");
+        Expr y = Expr.col("y");
+        Expr sumY = Expr.sum(y);
+        Expr xSort = x.sort(true, false);
+        DataFrame aggregated =
+            dataFrame.aggregate(Collections.singletonList(x), Collections.singletonList(sumY));
+        DataFrame sorted = aggregated.sort(Collections.singletonList(xSort))) {
+      assertArrayEquals(new long[] {1, 3}, collectColumn(sorted, allocator, 0));
+      assertArrayEquals(new long[] {14, 18}, collectColumn(sorted, allocator, 1));
+    }
+  }
+
+  @Test
+  public void testDistinct(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = readTestData(context, tempDir);
+        DataFrame selected = dataFrame.selectColumns("x");
+        DataFrame distinct = selected.distinct();
+        Expr x = Expr.col("x");
+        Expr xSort = x.sort(true, false);
+        DataFrame sorted = distinct.sort(Collections.singletonList(xSort))) {
+      assertArrayEquals(new long[] {1, 3}, collectColumn(sorted, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testFilter(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame =
+            context.sql("SELECT y, y > 10 AS large FROM (VALUES (2), (12)) AS t(y)").join();
+        Expr large = Expr.col("large");
+        DataFrame filtered = dataFrame.filter(large);
+        DataFrame selected = filtered.selectColumns("y")) {
+      assertArrayEquals(new long[] {12}, collectColumn(selected, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testSelectMissingColumn(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        DataFrame dataFrame = readTestData(context, tempDir)) {
+      assertThrows(RuntimeException.class, () -> dataFrame.selectColumns("z"));
+    }
+  }
+
+  @Test
+  public void testJoin() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame left = readJoinLeft(context);
+        DataFrame right = readJoinRight(context);
+        DataFrame joined =
+            left.join(
+                right,
+                JoinType.INNER,
+                Collections.singletonList("id"),
+                Collections.singletonList("rid"));
+        Expr id = Expr.col("id");
+        Expr idSort = id.sort(true, false);
+        DataFrame sorted = joined.sort(Collections.singletonList(idSort));
+        DataFrame selected = sorted.selectColumns("id", "value")) {
+      assertArrayEquals(new long[] {1, 3}, collectColumn(selected, allocator, 0));
+      assertArrayEquals(new long[] {10, 30}, collectColumn(selected, allocator, 1));
+    }
+  }
+
+  @Test
+  public void testJoinWithFilter() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame left = readJoinLeft(context);
+        DataFrame right = readJoinRight(context);
+        Expr value = Expr.col("value");
+        Expr twenty = Expr.lit(20L);
+        Expr filter = value.gt(twenty);
+        DataFrame joined =
+            left.join(
+                right,
+                JoinType.LEFT_ANTI,
+                Collections.singletonList("id"),
+                Collections.singletonList("rid"),
+                filter);
+        Expr id = Expr.col("id");
+        Expr idSort = id.sort(true, false);
+        DataFrame sorted = joined.sort(Collections.singletonList(idSort))) {
+      assertArrayEquals(new long[] {1, 2}, collectColumn(sorted, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testJoinOn() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame left = readJoinLeft(context);
+        DataFrame right = readJoinRight(context);
+        Expr id = Expr.col("id");
+        Expr rid = Expr.col("rid");
+        Expr on = id.eq(rid);
+        DataFrame joined = left.joinOn(right, JoinType.FULL, Collections.singletonList(on));
+        Expr count = Expr.count(id);
+        DataFrame aggregated =
+            joined.aggregate(Collections.emptyList(), Collections.singletonList(count))) {
+      assertArrayEquals(new long[] {3}, collectColumn(aggregated, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testJoinMissingColumn() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        DataFrame left = readJoinLeft(context);
+        DataFrame right = readJoinRight(context)) {
+      assertThrows(
+          RuntimeException.class,
+          () ->
+              left.join(
+                  right,
+                  JoinType.INNER,
+                  Collections.singletonList("missing"),
+                  Collections.singletonList("rid")));
+    }
+  }
+
+  @Test
+  public void testSetOperations() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame left = context.sql("SELECT * FROM (VALUES (1), (2), (2)) AS l(a)").join();
+        DataFrame right = context.sql("SELECT * FROM (VALUES (2), (3)) AS r(b)").join();
+        Expr a = Expr.col("a");
+        Expr aSort = a.sort(true, false);
+        DataFrame union = left.union(right);
+        DataFrame unionSorted = union.sort(Collections.singletonList(aSort));
+        DataFrame unionDistinct = left.unionDistinct(right);
+        DataFrame unionDistinctSorted = unionDistinct.sort(Collections.singletonList(aSort));
+        DataFrame intersect = left.intersect(right);
+        DataFrame except = left.except(right)) {
+      assertArrayEquals(new long[] {1, 2, 2, 2, 3}, collectColumn(unionSorted, allocator, 0));
+      assertArrayEquals(new long[] {1, 2, 3}, collectColumn(unionDistinctSorted, allocator, 0));
+      assertArrayEquals(new long[] {2}, collectColumn(intersect, allocator, 0));
+      assertArrayEquals(new long[] {1}, collectColumn(except, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testSetOperationSchemaMismatch() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        DataFrame left = context.sql("SELECT 1 AS a").join();
+        DataFrame wider = context.sql("SELECT 1 AS a, 2 AS b").join();
+        DataFrame text = context.sql("SELECT 'x' AS a").join()) {
+      RuntimeException exception = assertThrows(RuntimeException.class, () -> left.union(wider));
+      assertTrue(exception.getMessage().contains("same number of columns"));
+      exception = assertThrows(RuntimeException.class, () -> left.intersect(text));
+      assertTrue(exception.getMessage().contains("matching column types"));
+      assertThrows(RuntimeException.class, () -> left.except(wider));
+      assertThrows(RuntimeException.class, () -> left.unionDistinct(text));
+    }
+  }
+
+  @Test
+  public void testSetOperationCoercesNumericTypes() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame left = context.sql("SELECT 1 AS a").join();
+        DataFrame right = context.sql("SELECT CAST(2 AS INT) AS a").join();
+        DataFrame union = left.union(right);
+        DataFrame unionSorted =
+            union.sort(Collections.singletonList(Expr.col("a").sort(true, false)))) {
+      assertArrayEquals(new long[] {1, 2}, collectColumn(unionSorted, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testGetSchema(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        DataFrame dataFrame = readTestData(context, tempDir);
+        Expr x = Expr.col("x");
+        Expr alias = x.alias("a");
+        Expr y = Expr.col("y");
+        DataFrame selected = dataFrame.select(Arrays.asList(alias, y))) {
+      Schema schema = selected.getSchema();
+      assertEquals(2, schema.getFields().size());
+      Field a = schema.getFields().get(0);
+      assertEquals("a", a.getName());
+      assertEquals(new ArrowType.Int(64, true), a.getType());
+      assertFalse(a.getMetadata().containsKey(DataFrame.QUALIFIER_METADATA_KEY));
+      Field yField = schema.getFields().get(1);
+      assertEquals("y", yField.getName());
+      assertEquals(new ArrowType.Int(64, true), yField.getType());
+    }
+  }
+
+  @Test
+  public void testGetSchemaQualifier() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        DataFrame table = context.sql("SELECT 1 AS a").join()) {
+      table.registerTable(context, "t").join();
+      try (DataFrame dataFrame = context.sql("SELECT a FROM t").join()) {
+        Field a = dataFrame.getSchema().getFields().get(0);
+        assertEquals("a", a.getName());
+        assertEquals("t", a.getMetadata().get(DataFrame.QUALIFIER_METADATA_KEY));
+      }
+    }
+  }
+
+  private static DataFrame readJoinLeft(SessionContext context) {
+    return context.sql("SELECT * FROM (VALUES (1), (2), (3)) AS l(id)").join();
+  }
+
+  private static DataFrame readJoinRight(SessionContext context) {
+    return context.sql("SELECT * FROM (VALUES (1, 10), (3, 30), (4, 40)) AS r(rid, value)").join();
+  }
+
+  static DataFrame readTestData(SessionContext context, Path tempDir) throws Exception {
+    Path csvFilePath = tempDir.resolve("data.csv");
+    List<String> lines = Arrays.asList("x,y", "1,2", "3,4", "1,12", "3,14");
+    Files.write(csvFilePath, lines);
+    return context.readCsv(csvFilePath).join();
+  }
+
+  static long[] collectColumn(DataFrame dataFrame, BufferAllocator allocator, int column)
+      throws Exception {
+    List<Long> values = new ArrayList<>();
+    try (ArrowReader reader = dataFrame.collect(allocator).join()) {
+      VectorSchemaRoot root = reader.getVectorSchemaRoot();
+      while (reader.loadNextBatch()) {
+        BigIntVector vector = (BigIntVector) root.getVector(column);
+        for (int row = 0; row < root.getRowCount(); ++row) {
+          values.add(vector.get(row));
+        }
+      }
+    }
+    return values.stream().mapToLong(Long::longValue).toArray();
+  }
+}
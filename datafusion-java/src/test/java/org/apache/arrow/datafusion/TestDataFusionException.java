@@ -0,0 +1,109 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.util.Collections;
+import java.util.concurrent.CompletionException;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.junit.jupiter.api.Test;
+
+public class TestDataFusionException {
+  @Test
+  public void testSqlParseError() throws Exception {
+    try (SessionContext context = SessionContexts.create()) {
+      CompletionException exception =
+          assertThrows(CompletionException.class, () -> context.sql("SELEC 1").join());
+      QueryException cause = assertInstanceOf(QueryException.class, exception.getCause());
+      assertEquals(ErrorKind.SQL, cause.getKind());
+    }
+  }
+
+  @Test
+  public void testMissingColumnError() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        DataFrame dataFrame = context.sql("SELECT 1 AS a").join()) {
+      QueryException exception =
+          assertThrows(QueryException.class, () -> dataFrame.selectColumns("missing"));
+      assertTrue(exception.getKind().isQueryError());
+      assertTrue(exception.getMessage().contains("missing"));
+    }
+  }
+
+  @Test
+  public void testExecutionError() throws Exception {
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdf(
+          allocator,
+          "fail",
+          Collections.singletonList(new ArrowType.Int(64, true)),
+          new ArrowType.Int(64, true),
+          Volatility.VOLATILE,
+          (udfAllocator, args) -> {
+            throw new IllegalStateException("bad state");
+          });
+      try (DataFrame dataFrame = context.sql("SELECT fail(1)").join()) {
+        CompletionException exception =
+            assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+        DataFusionException cause =
+            assertInstanceOf(DataFusionException.class, exception.getCause());
+        assertFalse(cause instanceof QueryException);
+        assertFalse(cause.getKind().isQueryError());
+        assertTrue(cause.getMessage().contains("bad state"));
+      }
+    }
+  }
+
+  @Test
+  public void testNativePanic() throws Exception {
+    try (SessionContext context = SessionContexts.create()) {
+      DataFusionException exception =
+          assertThrows(DataFusionException.class, () -> context.sql(null));
+      assertEquals(ErrorKind.INTERNAL, exception.getKind());
+      assertTrue(exception.getMessage().contains("panicked"));
+      // The context is still usable after the panic
+      try (DataFrame dataFrame = context.sql("SELECT 1").join()) {
+        assertNotNull(dataFrame);
+      }
+    }
+  }
+
+  @Test
+  public void testUseAfterClose() throws Exception {
+    try (SessionContext context = SessionContexts.create()) {
+      DataFrame dataFrame = context.sql("SELECT 1 AS a").join();
+      dataFrame.close();
+      DataFusionException exception =
+          assertThrows(DataFusionException.class, () -> dataFrame.selectColumns("a"));
+      assertEquals(ErrorKind.EXECUTION, exception.getKind());
+      assertTrue(exception.getMessage().contains("Closed or invalid handle"));
+      // A new DataFrame may reuse the closed handle's slot but not its handle value
+      try (DataFrame other = context.sql("SELECT 2 AS a").join()) {
+        assertNotEquals(dataFrame.getPointer(), other.getPointer());
+        assertThrows(DataFusionException.class, () -> dataFrame.selectColumns("a"));
+      }
+    }
+  }
+
+  @Test
+  public void testCreateException() {
+    Throwable root = new RuntimeException("root");
+    DataFusionException plan = DataFusionException.create(ErrorKind.PLAN.getId(), "plan", root);
+    assertInstanceOf(QueryException.class, plan);
+    assertEquals(ErrorKind.PLAN, plan.getKind());
+    assertEquals("plan", plan.getMessage());
+    assertSame(root, plan.getCause());
+
+    DataFusionException resources =
+        DataFusionException.create(ErrorKind.RESOURCES_EXHAUSTED.getId(), "memory", null);
+    assertInstanceOf(ResourcesExhaustedException.class, resources);
+
+    DataFusionException io = DataFusionException.create(ErrorKind.IO.getId(), "io", null);
+    assertEquals(DataFusionException.class, io.getClass());
+    assertEquals(ErrorKind.IO, io.getKind());
+
+    assertEquals(ErrorKind.EXTERNAL, DataFusionException.create(-1, "unknown", null).getKind());
+  }
+}
@@ -9,6 +9,7 @@ import java.nio.file.Path;
 import java.nio.file.Paths;
 import java.util.Arrays;
 import java.util.List;
+import java.util.concurrent.CompletionException;
 import org.apache.arrow.memory.BufferAllocator;
 import org.apache.arrow.memory.RootAllocator;
 import org.apache.arrow.vector.BigIntVector;
@@ -57,6 +58,31 @@ public class TestExecuteStream {
     }
   }
 
+  @Test
+  public void cancelStream(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator()) {
+      Path csvFilePath = tempDir.resolve("data.csv");
+
+      List<String> lines = Arrays.asList("x,y", "1,2", "3,4");
+      Files.write(csvFilePath, lines);
+
+      context.registerCsv("test", csvFilePath).join();
+
+      try (RecordBatchStream stream =
+          context
+              .sql("SELECT x, y FROM test")
+              .thenComposeAsync(df -> df.executeStream(allocator))
+              .join()) {
+        stream.cancel();
+        CompletionException exception =
+            assertThrows(CompletionException.class, () -> stream.loadNextBatch().join());
+        assertInstanceOf(DataFusionException.class, exception.getCause());
+        assertTrue(exception.getCause().getMessage().contains("cancelled"));
+      }
+    }
+  }
+
   @Test
   public void readDictionaryData() throws Exception {
     try (SessionContext context =
@@ -0,0 +1,183 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.math.BigDecimal;
+import java.nio.file.Path;
+import java.time.LocalDate;
+import java.util.Arrays;
+import java.util.Collections;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.BigIntVector;
+import org.apache.arrow.vector.BitVector;
+import org.apache.arrow.vector.DateDayVector;
+import org.apache.arrow.vector.DecimalVector;
+import org.apache.arrow.vector.Float8Vector;
+import org.apache.arrow.vector.VarCharVector;
+import org.apache.arrow.vector.VectorSchemaRoot;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+public class TestExpr {
+  @Test
+  public void testArithmeticAndComparison(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = TestDataFrame.readTestData(context, tempDir);
+        Expr x = Expr.col("x");
+        Expr y = Expr.col("y");
+        Expr ten = Expr.lit(10L);
+        Expr two = Expr.lit(2L);
+        Expr seven = Expr.lit(7L);
+        Expr product = x.multiply(y);
+        Expr sum = product.plus(ten);
+        Expr result = sum.modulo(seven);
+        Expr large = y.gt(ten);
+        Expr small = x.lt(two);
+        Expr predicate = large.and(small);
+        DataFrame filtered = dataFrame.filter(predicate);
+        DataFrame selected = filtered.select(Collections.singletonList(result))) {
+      // x = 1, y = 12: (1 * 12 + 10) % 7
+      assertArrayEquals(new long[] {1}, TestDataFrame.collectColumn(selected, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testCaseWhen(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = TestDataFrame.readTestData(context, tempDir);
+        Expr x = Expr.col("x");
+        Expr y = Expr.col("y");
+        Expr one = Expr.lit(1L);
+        Expr twelve = Expr.lit(12L);
+        Expr large = y.gtEq(twelve);
+        Expr hundred = Expr.lit(100L);
+        Expr zero = Expr.lit(0L);
+        Expr searched = Expr.when(large, hundred).otherwise(zero);
+        Expr simple = Expr.caseOf(x).when(one, hundred).end();
+        Expr ySort = y.sort(true, false);
+        DataFrame sorted = dataFrame.sort(Collections.singletonList(ySort));
+        DataFrame sortedSelected = sorted.select(Arrays.asList(searched, simple))) {
+      assertArrayEquals(
+          new long[] {0, 0, 100, 100}, TestDataFrame.collectColumn(sortedSelected, allocator, 0));
+      try (ArrowReader reader = sortedSelected.collect(allocator).join()) {
+        VectorSchemaRoot root = reader.getVectorSchemaRoot();
+        assertTrue(reader.loadNextBatch());
+        BigIntVector vector = (BigIntVector) root.getVector(1);
+        assertEquals(100, vector.get(0));
+        assertTrue(vector.isNull(1));
+        assertEquals(100, vector.get(2));
+        assertTrue(vector.isNull(3));
+      }
+    }
+  }
+
+  @Test
+  public void testNullChecksAndLists(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = TestDataFrame.readTestData(context, tempDir);
+        Expr y = Expr.col("y");
+        Expr four = Expr.lit(4L);
+        Expr twelve = Expr.lit(12L);
+        Expr inList = y.inList(Arrays.asList(four, twelve));
+        Expr notNull = y.isNotNull();
+        Expr predicate = inList.and(notNull);
+        Expr ySort = y.sort(true, false);
+        DataFrame filtered = dataFrame.filter(predicate);
+        DataFrame sorted = filtered.sort(Collections.singletonList(ySort));
+        DataFrame selected = sorted.selectColumns("y");
+        Expr notInList = y.notInList(Arrays.asList(four, twelve));
+        DataFrame excluded = dataFrame.filter(notInList);
+        DataFrame excludedSorted = excluded.sort(Collections.singletonList(ySort));
+        DataFrame excludedSelected = excludedSorted.selectColumns("y");
+        Expr isNull = y.isNull();
+        DataFrame nulls = dataFrame.filter(isNull)) {
+      assertArrayEquals(new long[] {4, 12}, TestDataFrame.collectColumn(selected, allocator, 0));
+      assertArrayEquals(
+          new long[] {2, 14}, TestDataFrame.collectColumn(excludedSelected, allocator, 0));
+      assertArrayEquals(new long[] {}, TestDataFrame.collectColumn(nulls, allocator, 0));
+    }
+  }
+
+  @Test
+  public void testLiteralsAndFunctions() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = context.sql("SELECT 'apple' AS s").join();
+        Expr s = Expr.col("s");
+        Expr pattern = Expr.lit("a%");
+        Expr like = s.like(pattern);
+        Expr notLike = s.notLike(pattern);
+        Expr upper = Expr.function("upper", s);
+        Expr decimal = Expr.lit(new BigDecimal("12.34"));
+        Expr date = Expr.lit(LocalDate.of(2023, 1, 2));
+        Expr half = Expr.lit(0.5);
+        Expr casted = half.cast(new ArrowType.Utf8());
+        DataFrame selected =
+            dataFrame.select(Arrays.asList(like, notLike, upper, decimal, date, casted));
+        ArrowReader reader = selected.collect(allocator).join()) {
+      VectorSchemaRoot root = reader.getVectorSchemaRoot();
+      assertTrue(reader.loadNextBatch());
+      assertEquals(1, root.getRowCount());
+      assertEquals(1, ((BitVector) root.getVector(0)).get(0));
+      assertEquals(0, ((BitVector) root.getVector(1)).get(0));
+      assertEquals("APPLE", ((VarCharVector) root.getVector(2)).getObject(0).toString());
+      assertEquals(
+          new BigDecimal("12.34"), ((DecimalVector) root.getVector(3)).getObject(0));
+      assertEquals(
+          LocalDate.of(2023, 1, 2).toEpochDay(),
+          ((DateDayVector) root.getVector(4)).get(0));
+      assertEquals("0.5", ((VarCharVector) root.getVector(5)).getObject(0).toString());
+    }
+  }
+
+  @Test
+  public void testUnknownFunction() throws Exception {
+    try (Expr x = Expr.col("x")) {
+      assertThrows(RuntimeException.class, () -> Expr.function("not_a_function", x));
+    }
+  }
+
+  @Test
+  public void testDecimalLiterals() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = context.sql("SELECT 1 AS x").join();
+        Expr small = Expr.lit(new BigDecimal("0.05"));
+        Expr negativeScale = Expr.lit(new BigDecimal("1E+3"));
+        DataFrame selected = dataFrame.select(Arrays.asList(small, negativeScale));
+        ArrowReader reader = selected.collect(allocator).join()) {
+      VectorSchemaRoot root = reader.getVectorSchemaRoot();
+      assertTrue(reader.loadNextBatch());
+      assertEquals(new BigDecimal("0.05"), ((DecimalVector) root.getVector(0)).getObject(0));
+      assertEquals(new BigDecimal("1000"), ((DecimalVector) root.getVector(1)).getObject(0));
+    }
+  }
+
+  @Test
+  public void testDecimalPrecisionOutOfRange() throws Exception {
+    BigDecimal value = new BigDecimal("1234567890123456789012345678901234567890");
+    assertThrows(DataFusionException.class, () -> Expr.lit(value));
+  }
+
+  @Test
+  public void testFloatDivision() throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        DataFrame dataFrame = context.sql("SELECT 1.0 AS a").join();
+        Expr a = Expr.col("a");
+        Expr four = Expr.lit(4.0);
+        Expr quotient = a.divide(four);
+        DataFrame selected = dataFrame.select(Collections.singletonList(quotient));
+        ArrowReader reader = selected.collect(allocator).join()) {
+      VectorSchemaRoot root = reader.getVectorSchemaRoot();
+      assertTrue(reader.loadNextBatch());
+      assertEquals(0.25, ((Float8Vector) root.getVector(0)).get(0));
+    }
+  }
+}
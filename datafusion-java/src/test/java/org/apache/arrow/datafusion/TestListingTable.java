@@ -56,6 +56,36 @@ public class TestListingTable {
     }
   }
 
+  @Test
+  public void testJsonListingTable(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator()) {
+      Path dataDir = tempDir.resolve("data");
+      Files.createDirectories(dataDir);
+
+      Path jsonFilePath0 = dataDir.resolve("0.json");
+      List<String> lines = Arrays.asList("{\"x\": 1, \"y\": 2}", "{\"x\": 3, \"y\": 4}");
+      Files.write(jsonFilePath0, lines);
+
+      Path jsonFilePath1 = dataDir.resolve("1.json");
+      lines = Arrays.asList("{\"x\": 1, \"y\": 12}", "{\"x\": 3, \"y\": 14}");
+      Files.write(jsonFilePath1, lines);
+
+      try (JsonFormat format = new JsonFormat();
+          ListingOptions listingOptions =
+              ListingOptions.builder(format).withFileExtension(".json").build();
+          ListingTableConfig tableConfig =
+              ListingTableConfig.builder(dataDir)
+                  .withListingOptions(listingOptions)
+                  .build(context)
+                  .join();
+          ListingTable listingTable = new ListingTable(tableConfig)) {
+        context.registerTable("test", listingTable);
+        testQuery(context, allocator);
+      }
+    }
+  }
+
   @Test
   public void testParquetListingTable(@TempDir Path tempDir) throws Exception {
     try (SessionContext context = SessionContexts.create();
@@ -0,0 +1,92 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.nio.charset.StandardCharsets;
+import java.util.Arrays;
+import java.util.Collections;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.BigIntVector;
+import org.apache.arrow.vector.VarCharVector;
+import org.apache.arrow.vector.VectorSchemaRoot;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.apache.arrow.vector.types.pojo.Field;
+import org.apache.arrow.vector.types.pojo.Schema;
+import org.junit.jupiter.api.Test;
+
+public class TestMemTable {
+  private static final Schema SCHEMA =
+      new Schema(
+          Arrays.asList(
+              Field.nullable("id", new ArrowType.Int(64, true)),
+              Field.nullable("name", new ArrowType.Utf8())));
+
+  @Test
+  public void testQueryMemTable() throws Exception {
+    // The context must be closed before the allocator, as it holds the exported table data
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create();
+        VectorSchemaRoot batch0 = createBatch(allocator, new long[] {1, 2}, "a", "b");
+        VectorSchemaRoot batch1 = createBatch(allocator, new long[] {3}, "c");
+        MemTable memTable = new MemTable(allocator, SCHEMA, Arrays.asList(batch0, batch1))) {
+      context.registerTable("names", memTable);
+      try (DataFrame dataFrame =
+              context.sql("SELECT id, name FROM names WHERE id > 1 ORDER BY id").join();
+          ArrowReader reader = dataFrame.collect(allocator).join()) {
+        VectorSchemaRoot root = reader.getVectorSchemaRoot();
+        assertTrue(reader.loadNextBatch());
+        assertEquals(2, root.getRowCount());
+        BigIntVector idValues = (BigIntVector) root.getVector(0);
+        VarCharVector nameValues = (VarCharVector) root.getVector(1);
+        assertEquals(2, idValues.get(0));
+        assertEquals(3, idValues.get(1));
+        assertEquals("b", new String(nameValues.get(0), StandardCharsets.UTF_8));
+        assertEquals("c", new String(nameValues.get(1), StandardCharsets.UTF_8));
+        assertFalse(reader.loadNextBatch());
+      }
+    }
+  }
+
+  @Test
+  public void testEmptyMemTable() throws Exception {
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create();
+        MemTable memTable = new MemTable(allocator, SCHEMA, Collections.emptyList())) {
+      context.registerTable("names", memTable);
+      try (DataFrame dataFrame = context.sql("SELECT count(*) FROM names").join();
+          ArrowReader reader = dataFrame.collect(allocator).join()) {
+        assertTrue(reader.loadNextBatch());
+        assertEquals(0, ((BigIntVector) reader.getVectorSchemaRoot().getVector(0)).get(0));
+      }
+    }
+  }
+
+  @Test
+  public void testSchemaMismatch() throws Exception {
+    Schema otherSchema =
+        new Schema(Collections.singletonList(Field.nullable("x", new ArrowType.Utf8())));
+    try (BufferAllocator allocator = new RootAllocator();
+        VectorSchemaRoot batch = createBatch(allocator, new long[] {1}, "a")) {
+      assertThrows(
+          RuntimeException.class,
+          () -> new MemTable(allocator, otherSchema, Collections.singletonList(batch)));
+    }
+  }
+
+  private static VectorSchemaRoot createBatch(
+      BufferAllocator allocator, long[] ids, String... names) {
+    VectorSchemaRoot root = VectorSchemaRoot.create(SCHEMA, allocator);
+    BigIntVector idVector = (BigIntVector) root.getVector(0);
+    VarCharVector nameVector = (VarCharVector) root.getVector(1);
+    idVector.allocateNew(ids.length);
+    nameVector.allocateNew(ids.length);
+    for (int i = 0; i < ids.length; ++i) {
+      idVector.set(i, ids[i]);
+      nameVector.set(i, names[i].getBytes(StandardCharsets.UTF_8));
+    }
+    root.setRowCount(ids.length);
+    return root;
+  }
+}
@@ -1,20 +1,41 @@
 package org.apache.arrow.datafusion;
 
 import static org.junit.jupiter.api.Assertions.*;
+import static org.junit.jupiter.api.Assumptions.assumeTrue;
 
+import java.io.OutputStream;
+import java.nio.charset.StandardCharsets;
 import java.nio.file.Files;
 import java.nio.file.Path;
 import java.util.Arrays;
+import java.util.Collections;
 import java.util.List;
+import java.util.concurrent.CompletableFuture;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.ExecutionException;
+import java.util.concurrent.TimeUnit;
+import java.util.zip.GZIPOutputStream;
 import org.apache.arrow.memory.BufferAllocator;
 import org.apache.arrow.memory.RootAllocator;
 import org.apache.arrow.vector.BigIntVector;
 import org.apache.arrow.vector.VectorSchemaRoot;
 import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.apache.arrow.vector.types.pojo.Field;
+import org.apache.arrow.vector.types.pojo.Schema;
 import org.junit.jupiter.api.Test;
 import org.junit.jupiter.api.io.TempDir;
 
 public class TestQuery {
+  private static final String AVRO_SCHEMA =
+      "{\"namespace\": \"org.example\","
+          + "\"type\": \"record\","
+          + "\"name\": \"record_name\","
+          + "\"fields\": ["
+          + " {\"name\": \"x\", \"type\": \"long\"},"
+          + " {\"name\": \"y\", \"type\": \"long\"}"
+          + " ]}";
+
   @Test
   public void testQueryCsv(@TempDir Path tempDir) throws Exception {
     try (SessionContext context = SessionContexts.create();
@@ -29,24 +50,58 @@ public class TestQuery {
     }
   }
 
+  @Test
+  public void testQueryCsvWithOptions(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        CsvReadOptions options = new CsvReadOptions()) {
+      Path csvFilePath = tempDir.resolve("data.csv");
+
+      List<String> lines = Arrays.asList("1|2", "3|4");
+      Files.write(csvFilePath, lines);
+
+      Schema schema =
+          new Schema(
+              Arrays.asList(
+                  Field.nullable("x", new ArrowType.Int(64, true)),
+                  Field.nullable("y", new ArrowType.Int(64, true))));
+      options.withHasHeader(false).withDelimiter('|').withSchema(schema);
+
+      context.registerCsv("test", csvFilePath, options).join();
+      testQuery(context, allocator);
+    }
+  }
+
+  @Test
+  public void testReadCompressedCsv(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        CsvReadOptions options = new CsvReadOptions()) {
+      Path csvFilePath = tempDir.resolve("data.csv.gz");
+
+      try (OutputStream output = new GZIPOutputStream(Files.newOutputStream(csvFilePath))) {
+        output.write("x,y\n1,2\n3,4\n".getBytes(StandardCharsets.UTF_8));
+      }
+
+      options.withFileExtension(".csv.gz").withFileCompressionType(FileCompressionType.GZIP);
+
+      context
+          .readCsv(csvFilePath, options)
+          .thenComposeAsync(df -> df.registerTable(context, "test"))
+          .join();
+      testQuery(context, allocator);
+    }
+  }
+
   @Test
   public void testQueryParquet(@TempDir Path tempDir) throws Exception {
     try (SessionContext context = SessionContexts.create();
         BufferAllocator allocator = new RootAllocator()) {
       Path parquetFilePath = tempDir.resolve("data.parquet");
 
-      String schema =
-          "{\"namespace\": \"org.example\","
-              + "\"type\": \"record\","
-              + "\"name\": \"record_name\","
-              + "\"fields\": ["
-              + " {\"name\": \"x\", \"type\": \"long\"},"
-              + " {\"name\": \"y\", \"type\": \"long\"}"
-              + " ]}";
-
       ParquetWriter.writeParquet(
           parquetFilePath,
-          schema,
+          AVRO_SCHEMA,
           2,
           (i, record) -> {
             record.put("x", i * 2 + 1);
@@ -58,6 +113,154 @@ public class TestQuery {
     }
   }
 
+  @Test
+  public void testReadParquet(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator()) {
+      Path parquetFilePath = tempDir.resolve("data.parquet");
+
+      ParquetWriter.writeParquet(
+          parquetFilePath,
+          AVRO_SCHEMA,
+          2,
+          (i, record) -> {
+            record.put("x", i * 2 + 1);
+            record.put("y", i * 2 + 2);
+          });
+
+      context
+          .readParquet(parquetFilePath)
+          .thenComposeAsync(df -> df.registerTable(context, "test"))
+          .join();
+      testQuery(context, allocator);
+    }
+  }
+
+  @Test
+  public void testReadJson(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator()) {
+      Path jsonFilePath = tempDir.resolve("data.json");
+
+      List<String> lines = Arrays.asList("{\"x\": 1, \"y\": 2}", "{\"x\": 3, \"y\": 4}");
+      Files.write(jsonFilePath, lines);
+
+      context
+          .readJson(jsonFilePath)
+          .thenComposeAsync(df -> df.registerTable(context, "test"))
+          .join();
+      testQuery(context, allocator);
+    }
+  }
+
+  @Test
+  public void testQueryCompressedJson(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        NdJsonReadOptions options = new NdJsonReadOptions()) {
+      Path jsonFilePath = tempDir.resolve("data.ndjson.gz");
+
+      try (OutputStream output = new GZIPOutputStream(Files.newOutputStream(jsonFilePath))) {
+        output.write(
+            "{\"x\": 1, \"y\": 2}\n{\"x\": 3, \"y\": 4}\n".getBytes(StandardCharsets.UTF_8));
+      }
+
+      options
+          .withFileExtension(".ndjson.gz")
+          .withFileCompressionType(FileCompressionType.GZIP)
+          .withSchemaInferMaxRecords(1);
+
+      context.registerJson("test", jsonFilePath, options).join();
+      testQuery(context, allocator);
+    }
+  }
+
+  @Test
+  public void testQueryAvro(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator()) {
+      Path avroFilePath = tempDir.resolve("data.avro");
+
+      AvroWriter.writeAvro(
+          avroFilePath,
+          AVRO_SCHEMA,
+          2,
+          (i, record) -> {
+            record.put("x", (long) (i * 2 + 1));
+            record.put("y", (long) (i * 2 + 2));
+          });
+
+      context.registerAvro("test", avroFilePath).join();
+      testQuery(context, allocator);
+    }
+  }
+
+  @Test
+  public void testReadAvro(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator()) {
+      Path avroFilePath = tempDir.resolve("data.avro");
+
+      AvroWriter.writeAvro(
+          avroFilePath,
+          AVRO_SCHEMA,
+          2,
+          (i, record) -> {
+            record.put("x", (long) (i * 2 + 1));
+            record.put("y", (long) (i * 2 + 2));
+          });
+
+      context
+          .readAvro(avroFilePath)
+          .thenComposeAsync(df -> df.registerTable(context, "test"))
+          .join();
+      testQuery(context, allocator);
+    }
+  }
+
+  @Test
+  public void testQueryPartitionedParquet(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create();
+        BufferAllocator allocator = new RootAllocator();
+        ParquetReadOptions options = new ParquetReadOptions()) {
+      Path dataDir = tempDir.resolve("data");
+      for (String partition : Arrays.asList("a", "b")) {
+        int offset = partition.equals("a") ? 0 : 10;
+        ParquetWriter.writeParquet(
+            dataDir.resolve("part=" + partition).resolve("data.pq"),
+            AVRO_SCHEMA,
+            2,
+            (i, record) -> {
+              record.put("x", i * 2 + 1);
+              record.put("y", i * 2 + 2 + offset);
+            });
+      }
+
+      options
+          .withFileExtension(".pq")
+          .withTablePartitionCols(
+              Collections.singletonList(Field.nullable("part", new ArrowType.Utf8())));
+
+      context.registerParquet("test", dataDir, options).join();
+
+      try (ArrowReader reader =
+          context
+              .sql("SELECT y FROM test WHERE x = 3 ORDER BY y")
+              .thenComposeAsync(df -> df.collect(allocator))
+              .join()) {
+        VectorSchemaRoot root = reader.getVectorSchemaRoot();
+        assertTrue(reader.loadNextBatch());
+
+        assertEquals(2, root.getRowCount());
+        BigIntVector yValues = (BigIntVector) root.getVector(0);
+        assertEquals(4, yValues.get(0));
+        assertEquals(14, yValues.get(1));
+
+        assertFalse(reader.loadNextBatch());
+      }
+    }
+  }
+
   private static void testQuery(SessionContext context, BufferAllocator allocator)
       throws Exception {
     try (ArrowReader reader =
@@ -76,4 +279,81 @@ public class TestQuery {
       assertFalse(reader.loadNextBatch());
     }
   }
+
+  @Test
+  public void testCollectDoesNotBlockCaller() throws Exception {
+    CountDownLatch started = new CountDownLatch(1);
+    CountDownLatch release = new CountDownLatch(1);
+    // The context must be closed before the allocator used by the UDF
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdf(
+          allocator,
+          "wait_for_release",
+          Collections.singletonList(new ArrowType.Int(64, true)),
+          new ArrowType.Int(64, true),
+          Volatility.VOLATILE,
+          (udfAllocator, args) -> {
+            started.countDown();
+            assertTrue(release.await(10, TimeUnit.SECONDS));
+            BigIntVector input = (BigIntVector) args.get(0);
+            BigIntVector result = new BigIntVector("result", udfAllocator);
+            result.allocateNew(input.getValueCount());
+            for (int i = 0; i < input.getValueCount(); ++i) {
+              result.set(i, input.get(i));
+            }
+            result.setValueCount(input.getValueCount());
+            return result;
+          });
+      try (DataFrame dataFrame = context.sql("SELECT wait_for_release(1)").join()) {
+        CompletableFuture<ArrowReader> future = dataFrame.collect(allocator);
+        assertTrue(started.await(10, TimeUnit.SECONDS));
+        // The query is still running, but the calling thread was not blocked
+        assertFalse(future.isDone());
+        release.countDown();
+        try (ArrowReader reader = future.join()) {
+          assertTrue(reader.loadNextBatch());
+          BigIntVector result = (BigIntVector) reader.getVectorSchemaRoot().getVector(0);
+          assertEquals(1, result.get(0));
+        }
+      }
+    }
+  }
+
+  @Test
+  public void testCloseFailsRunningQuery() throws Exception {
+    // A second runtime thread must be free to drop the pending query while the UDF is blocked
+    assumeTrue(java.lang.Runtime.getRuntime().availableProcessors() > 1);
+    CountDownLatch started = new CountDownLatch(1);
+    CountDownLatch release = new CountDownLatch(1);
+    // The UDF is still running after the context is closed, so its allocator is not closed
+    BufferAllocator udfAllocator = new RootAllocator();
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdf(
+          udfAllocator,
+          "wait_for_release",
+          Collections.singletonList(new ArrowType.Int(64, true)),
+          new ArrowType.Int(64, true),
+          Volatility.VOLATILE,
+          (unused, args) -> {
+            started.countDown();
+            release.await(10, TimeUnit.SECONDS);
+            throw new IllegalStateException("The query should have been cancelled");
+          });
+      // Each side of the union runs in its own task, while the collect task waits for them
+      try (DataFrame dataFrame =
+          context.sql("SELECT wait_for_release(1) AS x UNION ALL SELECT 2 AS x").join()) {
+        CompletableFuture<ArrowReader> future = dataFrame.collect(allocator);
+        assertTrue(started.await(10, TimeUnit.SECONDS));
+        context.close();
+        ExecutionException exception =
+            assertThrows(ExecutionException.class, () -> future.get(10, TimeUnit.SECONDS));
+        assertInstanceOf(DataFusionException.class, exception.getCause());
+        assertTrue(exception.getCause().getMessage().contains("runtime was shut down"));
+      } finally {
+        release.countDown();
+      }
+    }
+  }
 }
@@ -0,0 +1,73 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.time.Duration;
+import java.util.Collections;
+import java.util.concurrent.atomic.AtomicReference;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.BigIntVector;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.junit.jupiter.api.Test;
+
+public class TestRuntimeBuilder {
+  @Test
+  public void testThreadNamePrefix() throws Exception {
+    RuntimeBuilder runtimeBuilder =
+        new RuntimeBuilder()
+            .withWorkerThreads(2)
+            .withMaxBlockingThreads(4)
+            .withThreadNamePrefix("test-runtime")
+            .withThreadStackSize(4 * 1024 * 1024)
+            .withThreadKeepAlive(Duration.ofSeconds(1));
+    assertTrue(evaluateThreadName(runtimeBuilder).startsWith("test-runtime-"));
+  }
+
+  @Test
+  public void testCurrentThread() throws Exception {
+    RuntimeBuilder runtimeBuilder =
+        new RuntimeBuilder().withCurrentThread().withThreadNamePrefix("test-current");
+    assertEquals("test-current-driver", evaluateThreadName(runtimeBuilder));
+  }
+
+  @Test
+  public void testInvalidOptions() {
+    assertThrows(IllegalArgumentException.class, () -> new RuntimeBuilder().withWorkerThreads(0));
+    assertThrows(
+        IllegalArgumentException.class,
+        () -> new RuntimeBuilder().withThreadKeepAlive(Duration.ofSeconds(-1)));
+    RuntimeBuilder currentThreadWorkers =
+        new RuntimeBuilder().withCurrentThread().withWorkerThreads(2);
+    assertThrows(IllegalStateException.class, () -> SessionContexts.create(currentThreadWorkers));
+  }
+
+  /** Get the name of the thread that a UDF is called on when running a query */
+  private static String evaluateThreadName(RuntimeBuilder runtimeBuilder) throws Exception {
+    AtomicReference<String> threadName = new AtomicReference<>();
+    // The context must be closed before the allocator used by the UDF
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create(runtimeBuilder)) {
+      context.registerUdf(
+          allocator,
+          "thread_name",
+          Collections.singletonList(new ArrowType.Int(64, true)),
+          new ArrowType.Int(64, true),
+          Volatility.VOLATILE,
+          (udfAllocator, args) -> {
+            threadName.set(Thread.currentThread().getName());
+            BigIntVector result = new BigIntVector("result", udfAllocator);
+            result.allocateNew(1);
+            result.set(0, 1);
+            result.setValueCount(1);
+            return result;
+          });
+      try (DataFrame dataFrame = context.sql("SELECT thread_name(1)").join();
+          ArrowReader reader = dataFrame.collect(allocator).join()) {
+        assertTrue(reader.loadNextBatch());
+      }
+    }
+    return threadName.get();
+  }
+}
@@ -0,0 +1,145 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+import java.util.concurrent.CompletionException;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.BigIntVector;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+public class TestRuntimeConfig {
+  private static final String GROUP_BY_QUERY = "SELECT x, COUNT(*) FROM test GROUP BY x";
+  private static final String SORT_QUERY = "SELECT x, y FROM test ORDER BY x DESC";
+
+  @Test
+  public void testMemoryLimitExceeded(@TempDir Path tempDir) throws Exception {
+    Path csvFilePath = writeCsv(tempDir);
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionConfig config = new SessionConfig();
+        RuntimeConfig runtimeConfig = new RuntimeConfig().withGreedyMemoryPool(1024);
+        SessionContext context = SessionContexts.withConfig(config, runtimeConfig)) {
+      context.registerCsv("test", csvFilePath).join();
+      try (DataFrame dataFrame = context.sql(GROUP_BY_QUERY).join()) {
+        CompletionException exception =
+            assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+        assertInstanceOf(ResourcesExhaustedException.class, exception.getCause());
+      }
+    }
+  }
+
+  @Test
+  public void testMemoryLimitNotExceeded(@TempDir Path tempDir) throws Exception {
+    Path csvFilePath = writeCsv(tempDir);
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionConfig config = new SessionConfig();
+        RuntimeConfig runtimeConfig = new RuntimeConfig().withFairSpillPool(64 * 1024 * 1024);
+        SessionContext context = SessionContexts.withConfig(config, runtimeConfig)) {
+      context.registerCsv("test", csvFilePath).join();
+      try (DataFrame dataFrame = context.sql(GROUP_BY_QUERY).join();
+          ArrowReader reader = dataFrame.collect(allocator).join()) {
+        int rowCount = 0;
+        while (reader.loadNextBatch()) {
+          rowCount += reader.getVectorSchemaRoot().getRowCount();
+        }
+        assertEquals(10000, rowCount);
+      }
+    }
+  }
+
+  @Test
+  public void testSortSpillsToDisk(@TempDir Path tempDir) throws Exception {
+    Path csvFilePath = writeCsv(tempDir, 200000);
+    Path spillDirectory = Files.createDirectory(tempDir.resolve("spill"));
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionConfig config = new SessionConfig();
+        RuntimeConfig runtimeConfig =
+            new RuntimeConfig()
+                .withFairSpillPool(1024 * 1024)
+                .withDiskManagerSpillDirectories(Collections.singletonList(spillDirectory))) {
+      // Use a single partition so the sort gets all of the memory pool
+      config.executionOptions().withTargetPartitions(1);
+      try (SessionContext context = SessionContexts.withConfig(config, runtimeConfig)) {
+        context.registerCsv("test", csvFilePath).join();
+        assertEquals(0, context.spillMetrics().spillCount());
+        try (DataFrame dataFrame = context.sql(SORT_QUERY).join();
+            ArrowReader reader = dataFrame.collect(allocator).join()) {
+          assertTrue(reader.loadNextBatch());
+          assertEquals(199999, ((BigIntVector) reader.getVectorSchemaRoot().getVector(0)).get(0));
+        }
+        SpillMetrics spillMetrics = context.spillMetrics();
+        assertTrue(spillMetrics.spillCount() > 0);
+        assertTrue(spillMetrics.spilledBytes() > 0);
+      }
+    }
+  }
+
+  @Test
+  public void testWriteRecordsSpills(@TempDir Path tempDir) throws Exception {
+    Path csvFilePath = writeCsv(tempDir, 200000);
+    Path spillDirectory = Files.createDirectory(tempDir.resolve("spill"));
+    try (SessionConfig config = new SessionConfig();
+        RuntimeConfig runtimeConfig =
+            new RuntimeConfig()
+                .withFairSpillPool(1024 * 1024)
+                .withDiskManagerSpillDirectories(Collections.singletonList(spillDirectory))) {
+      config.executionOptions().withTargetPartitions(1);
+      try (SessionContext context = SessionContexts.withConfig(config, runtimeConfig)) {
+        context.registerCsv("test", csvFilePath).join();
+        try (DataFrame dataFrame = context.sql(SORT_QUERY).join()) {
+          dataFrame.writeParquet(tempDir.resolve("sorted")).join();
+        }
+        assertTrue(context.spillMetrics().spillCount() > 0);
+      }
+    }
+  }
+
+  @Test
+  public void testSortWithDiskManagerDisabled(@TempDir Path tempDir) throws Exception {
+    Path csvFilePath = writeCsv(tempDir, 200000);
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionConfig config = new SessionConfig();
+        RuntimeConfig runtimeConfig =
+            new RuntimeConfig().withFairSpillPool(1024 * 1024).withDiskManagerDisabled()) {
+      config.executionOptions().withTargetPartitions(1);
+      try (SessionContext context = SessionContexts.withConfig(config, runtimeConfig)) {
+        context.registerCsv("test", csvFilePath).join();
+        try (DataFrame dataFrame = context.sql(SORT_QUERY).join()) {
+          CompletionException exception =
+              assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+          assertInstanceOf(ResourcesExhaustedException.class, exception.getCause());
+        }
+        assertEquals(0, context.spillMetrics().spillCount());
+      }
+    }
+  }
+
+  @Test
+  public void testInvalidPoolSize() throws Exception {
+    try (RuntimeConfig runtimeConfig = new RuntimeConfig()) {
+      assertThrows(IllegalArgumentException.class, () -> runtimeConfig.withGreedyMemoryPool(-1));
+    }
+  }
+
+  private static Path writeCsv(Path tempDir) throws Exception {
+    return writeCsv(tempDir, 10000);
+  }
+
+  private static Path writeCsv(Path tempDir, int rowCount) throws Exception {
+    Path csvFilePath = tempDir.resolve("data.csv");
+    List<String> lines = new ArrayList<>();
+    lines.add("x,y");
+    for (int i = 0; i < rowCount; ++i) {
+      lines.add(i + "," + (i * 2));
+    }
+    Files.write(csvFilePath, lines);
+    return csvFilePath;
+  }
+}
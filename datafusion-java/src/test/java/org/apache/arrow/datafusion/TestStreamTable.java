@@ -0,0 +1,65 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.io.ByteArrayInputStream;
+import java.io.ByteArrayOutputStream;
+import java.util.Collections;
+import java.util.concurrent.CompletionException;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.BigIntVector;
+import org.apache.arrow.vector.VectorSchemaRoot;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.ipc.ArrowStreamReader;
+import org.apache.arrow.vector.ipc.ArrowStreamWriter;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.apache.arrow.vector.types.pojo.Field;
+import org.apache.arrow.vector.types.pojo.Schema;
+import org.junit.jupiter.api.Test;
+
+public class TestStreamTable {
+  @Test
+  public void testQueryStreamTable() throws Exception {
+    // The context must be closed before the allocator, as it holds the exported stream
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      ArrowReader reader = createReader(allocator, 3, 4);
+      try (StreamTable streamTable = new StreamTable(allocator, reader)) {
+        context.registerTable("numbers", streamTable);
+      }
+      try (DataFrame dataFrame = context.sql("SELECT sum(x) FROM numbers").join();
+          ArrowReader result = dataFrame.collect(allocator).join()) {
+        assertTrue(result.loadNextBatch());
+        BigIntVector sum = (BigIntVector) result.getVectorSchemaRoot().getVector(0);
+        // Sum of 0 to 11
+        assertEquals(66, sum.get(0));
+      }
+      try (DataFrame dataFrame = context.sql("SELECT sum(x) FROM numbers").join()) {
+        assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+      }
+    }
+  }
+
+  private static ArrowReader createReader(BufferAllocator allocator, int batchCount, int batchSize)
+      throws Exception {
+    Schema schema =
+        new Schema(Collections.singletonList(Field.nullable("x", new ArrowType.Int(64, true))));
+    ByteArrayOutputStream output = new ByteArrayOutputStream();
+    try (VectorSchemaRoot root = VectorSchemaRoot.create(schema, allocator);
+        ArrowStreamWriter writer = new ArrowStreamWriter(root, null, output)) {
+      BigIntVector vector = (BigIntVector) root.getVector(0);
+      writer.start();
+      for (int batch = 0; batch < batchCount; ++batch) {
+        vector.allocateNew(batchSize);
+        for (int row = 0; row < batchSize; ++row) {
+          vector.set(row, (long) batch * batchSize + row);
+        }
+        root.setRowCount(batchSize);
+        writer.writeBatch();
+      }
+      writer.end();
+    }
+    return new ArrowStreamReader(new ByteArrayInputStream(output.toByteArray()), allocator);
+  }
+}
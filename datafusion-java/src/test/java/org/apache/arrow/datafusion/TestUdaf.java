@@ -0,0 +1,188 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+import java.util.concurrent.CompletionException;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.BigIntVector;
+import org.apache.arrow.vector.FieldVector;
+import org.apache.arrow.vector.Float8Vector;
+import org.apache.arrow.vector.VectorSchemaRoot;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.FloatingPointPrecision;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.junit.jupiter.api.Test;
+
+public class TestUdaf {
+  private static final ArrowType INT64 = new ArrowType.Int(64, true);
+  private static final ArrowType FLOAT64 =
+      new ArrowType.FloatingPoint(FloatingPointPrecision.DOUBLE);
+
+  @Test
+  public void testWeightedMean() throws Exception {
+    // The context must be closed before the allocator used by the UDAF
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdaf(
+          allocator,
+          "weighted_mean",
+          Arrays.asList(INT64, INT64),
+          FLOAT64,
+          Arrays.asList(INT64, INT64),
+          Volatility.IMMUTABLE,
+          WeightedMean::new);
+      String sql =
+          "SELECT k, weighted_mean(x, w) FROM "
+              + "(VALUES (1, 2, 1), (1, 5, 2), (2, 3, 1), (2, NULL, 4), (2, 7, 3)) AS t(k, x, w) "
+              + "GROUP BY k ORDER BY k";
+      try (DataFrame dataFrame = context.sql(sql).join();
+          ArrowReader reader = dataFrame.collect(allocator).join()) {
+        VectorSchemaRoot root = reader.getVectorSchemaRoot();
+        assertTrue(reader.loadNextBatch());
+        assertEquals(2, root.getRowCount());
+        BigIntVector keys = (BigIntVector) root.getVector(0);
+        Float8Vector means = (Float8Vector) root.getVector(1);
+        assertEquals(1, keys.get(0));
+        assertEquals(4.0, means.get(0), 1e-9);
+        assertEquals(2, keys.get(1));
+        assertEquals(6.0, means.get(1), 1e-9);
+      }
+    }
+  }
+
+  @Test
+  public void testUdafError() throws Exception {
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdaf(
+          allocator,
+          "fail",
+          Collections.singletonList(INT64),
+          INT64,
+          Collections.singletonList(INT64),
+          Volatility.IMMUTABLE,
+          () ->
+              new WeightedMean() {
+                @Override
+                public void updateBatch(List<FieldVector> values) {
+                  throw new IllegalArgumentException("bad input");
+                }
+              });
+      try (DataFrame dataFrame = context.sql("SELECT fail(1)").join()) {
+        CompletionException exception =
+            assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+        assertTrue(exception.getMessage().contains("bad input"));
+      }
+    }
+  }
+
+  @Test
+  public void testUdafWrongResultType() throws Exception {
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      // The accumulator returns a Float64 result rather than the declared Int64 type
+      context.registerUdaf(
+          allocator,
+          "wrong_type",
+          Arrays.asList(INT64, INT64),
+          INT64,
+          Arrays.asList(INT64, INT64),
+          Volatility.IMMUTABLE,
+          WeightedMean::new);
+      try (DataFrame dataFrame = context.sql("SELECT wrong_type(1, 2)").join()) {
+        CompletionException exception =
+            assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+        assertInstanceOf(DataFusionException.class, exception.getCause());
+        assertTrue(exception.getMessage().contains("but Int64 was expected"));
+      }
+    }
+  }
+
+  @Test
+  public void testUdafEmptyResult() throws Exception {
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdaf(
+          allocator,
+          "empty_result",
+          Arrays.asList(INT64, INT64),
+          FLOAT64,
+          Arrays.asList(INT64, INT64),
+          Volatility.IMMUTABLE,
+          () ->
+              new WeightedMean() {
+                @Override
+                public FieldVector evaluate(BufferAllocator allocator) {
+                  Float8Vector result = new Float8Vector("result", allocator);
+                  result.setValueCount(0);
+                  return result;
+                }
+              });
+      try (DataFrame dataFrame = context.sql("SELECT empty_result(1, 2)").join()) {
+        CompletionException exception =
+            assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+        assertTrue(exception.getMessage().contains("length 0 but 1 was expected"));
+      }
+    }
+  }
+
+  /** Computes the mean of x weighted by w, ignoring rows where either value is null */
+  private static class WeightedMean implements Accumulator {
+    private long weightedSum = 0;
+    private long totalWeight = 0;
+
+    @Override
+    public void updateBatch(List<FieldVector> values) {
+      BigIntVector x = (BigIntVector) values.get(0);
+      BigIntVector w = (BigIntVector) values.get(1);
+      for (int i = 0; i < x.getValueCount(); ++i) {
+        if (!x.isNull(i) && !w.isNull(i)) {
+          weightedSum += x.get(i) * w.get(i);
+          totalWeight += w.get(i);
+        }
+      }
+    }
+
+    @Override
+    public void mergeBatch(List<FieldVector> states) {
+      BigIntVector sums = (BigIntVector) states.get(0);
+      BigIntVector weights = (BigIntVector) states.get(1);
+      for (int i = 0; i < sums.getValueCount(); ++i) {
+        weightedSum += sums.get(i);
+        totalWeight += weights.get(i);
+      }
+    }
+
+    @Override
+    public List<FieldVector> state(BufferAllocator allocator) {
+      return Arrays.asList(
+          singleValue(allocator, "sum", weightedSum),
+          singleValue(allocator, "weight", totalWeight));
+    }
+
+    @Override
+    public FieldVector evaluate(BufferAllocator allocator) {
+      Float8Vector result = new Float8Vector("result", allocator);
+      result.allocateNew(1);
+      if (totalWeight == 0) {
+        result.setNull(0);
+      } else {
+        result.set(0, (double) weightedSum / totalWeight);
+      }
+      result.setValueCount(1);
+      return result;
+    }
+
+    private static BigIntVector singleValue(BufferAllocator allocator, String name, long value) {
+      BigIntVector vector = new BigIntVector(name, allocator);
+      vector.allocateNew(1);
+      vector.set(0, value);
+      vector.setValueCount(1);
+      return vector;
+    }
+  }
+}
@@ -0,0 +1,181 @@
+package org.apache.arrow.datafusion;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.concurrent.CompletionException;
+import org.apache.arrow.memory.BufferAllocator;
+import org.apache.arrow.memory.RootAllocator;
+import org.apache.arrow.vector.BigIntVector;
+import org.apache.arrow.vector.VarCharVector;
+import org.apache.arrow.vector.VectorSchemaRoot;
+import org.apache.arrow.vector.ipc.ArrowReader;
+import org.apache.arrow.vector.types.pojo.ArrowType;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+public class TestUdf {
+  private static final ArrowType INT64 = new ArrowType.Int(64, true);
+
+  @Test
+  public void testScalarUdf() throws Exception {
+    // The context must be closed before the allocator used by the UDF
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdf(
+          allocator,
+          "weighted_sum",
+          Arrays.asList(INT64, INT64),
+          INT64,
+          Volatility.IMMUTABLE,
+          (udfAllocator, args) -> {
+            BigIntVector x = (BigIntVector) args.get(0);
+            BigIntVector y = (BigIntVector) args.get(1);
+            BigIntVector result = new BigIntVector("result", udfAllocator);
+            result.allocateNew(x.getValueCount());
+            for (int i = 0; i < x.getValueCount(); ++i) {
+              if (x.isNull(i) || y.isNull(i)) {
+                result.setNull(i);
+              } else {
+                result.set(i, x.get(i) + 10 * y.get(i));
+              }
+            }
+            result.setValueCount(x.getValueCount());
+            return result;
+          });
+      String sql = "SELECT weighted_sum(x, y) FROM (VALUES (1, 2), (3, 4), (5, NULL)) AS t(x, y)";
+      try (DataFrame dataFrame = context.sql(sql).join();
+          ArrowReader reader = dataFrame.collect(allocator).join()) {
+        VectorSchemaRoot root = reader.getVectorSchemaRoot();
+        assertTrue(reader.loadNextBatch());
+        BigIntVector result = (BigIntVector) root.getVector(0);
+        assertEquals(3, root.getRowCount());
+        assertEquals(21, result.get(0));
+        assertEquals(43, result.get(1));
+        assertTrue(result.isNull(2));
+      }
+    }
+  }
+
+  @Test
+  public void testStringUdfWithScalarArgument() throws Exception {
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdf(
+          allocator,
+          "shout",
+          Collections.singletonList(new ArrowType.Utf8()),
+          new ArrowType.Utf8(),
+          Volatility.IMMUTABLE,
+          (udfAllocator, args) -> {
+            VarCharVector input = (VarCharVector) args.get(0);
+            VarCharVector result = new VarCharVector("result", udfAllocator);
+            result.allocateNew(input.getValueCount());
+            for (int i = 0; i < input.getValueCount(); ++i) {
+              String value = new String(input.get(i), StandardCharsets.UTF_8);
+              result.setSafe(i, (value.toUpperCase() + "!").getBytes(StandardCharsets.UTF_8));
+            }
+            result.setValueCount(input.getValueCount());
+            return result;
+          });
+      try (DataFrame dataFrame = context.sql("SELECT shout('hello')").join();
+          ArrowReader reader = dataFrame.collect(allocator).join()) {
+        assertTrue(reader.loadNextBatch());
+        VarCharVector result = (VarCharVector) reader.getVectorSchemaRoot().getVector(0);
+        assertEquals("HELLO!", new String(result.get(0), StandardCharsets.UTF_8));
+      }
+    }
+  }
+
+  @Test
+  public void testUdfError() throws Exception {
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdf(
+          allocator,
+          "fail",
+          Collections.singletonList(INT64),
+          INT64,
+          Volatility.VOLATILE,
+          (udfAllocator, args) -> {
+            throw new IllegalArgumentException("bad input");
+          });
+      try (DataFrame dataFrame = context.sql("SELECT fail(1)").join()) {
+        CompletionException exception =
+            assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+        assertTrue(exception.getMessage().contains("bad input"));
+      }
+    }
+  }
+
+  @Test
+  public void testUdfWrongReturnType() throws Exception {
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.registerUdf(
+          allocator,
+          "wrong_type",
+          Collections.singletonList(INT64),
+          new ArrowType.Utf8(),
+          Volatility.VOLATILE,
+          (udfAllocator, args) -> {
+            BigIntVector result = new BigIntVector("result", udfAllocator);
+            result.allocateNew(1);
+            result.set(0, 1);
+            result.setValueCount(1);
+            return result;
+          });
+      try (DataFrame dataFrame = context.sql("SELECT wrong_type(1)").join()) {
+        CompletionException exception =
+            assertThrows(CompletionException.class, () -> dataFrame.collect(allocator).join());
+        assertTrue(exception.getMessage().contains("was expected"));
+      }
+    }
+  }
+
+  @Test
+  public void testLoadUdfLibrary() throws Exception {
+    // Built from datafusion-jni/test-udf-library and copied next to the native library
+    String libraryDirectory = System.getProperty("datafusion.test.udfLibraryDir");
+    assertNotNull(libraryDirectory, "datafusion.test.udfLibraryDir is not set");
+    Path path =
+        Paths.get(libraryDirectory, System.mapLibraryName("datafusion_jni_test_udf_library"));
+    try (BufferAllocator allocator = new RootAllocator();
+        SessionContext context = SessionContexts.create()) {
+      context.loadUdfLibrary(path);
+      String sql = "SELECT add_one(x) FROM (VALUES (1), (41), (NULL)) AS t(x)";
+      try (DataFrame dataFrame = context.sql(sql).join();
+          ArrowReader reader = dataFrame.collect(allocator).join()) {
+        VectorSchemaRoot root = reader.getVectorSchemaRoot();
+        assertTrue(reader.loadNextBatch());
+        BigIntVector result = (BigIntVector) root.getVector(0);
+        assertEquals(3, root.getRowCount());
+        assertEquals(2, result.get(0));
+        assertEquals(42, result.get(1));
+        assertTrue(result.isNull(2));
+      }
+    }
+  }
+
+  @Test
+  public void testLoadMissingUdfLibrary(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create()) {
+      Path path = tempDir.resolve("missing_udfs.so");
+      assertThrows(RuntimeException.class, () -> context.loadUdfLibrary(path));
+    }
+  }
+
+  @Test
+  public void testLoadInvalidUdfLibrary(@TempDir Path tempDir) throws Exception {
+    try (SessionContext context = SessionContexts.create()) {
+      Path path = tempDir.resolve("invalid_udfs.so");
+      Files.write(path, "not a library".getBytes(StandardCharsets.UTF_8));
+      assertThrows(RuntimeException.class, () -> context.loadUdfLibrary(path));
+    }
+  }
+}
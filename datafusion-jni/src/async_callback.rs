@@ -0,0 +1,130 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+
+use datafusion::error::{DataFusionError, Result};
+use futures::FutureExt;
+use jni::objects::{JClass, JObject};
+use jni::sys::jlong;
+use jni::JNIEnv;
+use tokio_util::sync::CancellationToken;
+
+use crate::exception::{catch_panic, panic_error, throw_error};
+use crate::handle;
+use crate::java_callbacks::JavaCallbacks;
+use crate::runtime::TokioRuntime;
+
+/// Spawn a future onto the Tokio runtime rather than blocking the calling Java thread,
+/// then complete a Java callback with the result from the runtime thread that ran it.
+/// A panic in the future is passed to the callback as an error, as is a panic or Java exception
+/// while completing the callback.
+///
+/// Returns the handle of a cancellation token for the task, which is used by a Java NativeFuture
+/// to cancel it. Cancelling the task drops the future, releasing any resources it holds.
+/// The token is removed once the task completes. Shutting down the runtime fails the callbacks
+/// of any tasks that have not completed.
+pub fn spawn_with_callback<T, F, C>(
+    env: &mut JNIEnv,
+    runtime: &TokioRuntime,
+    callback: JObject,
+    future: F,
+    complete: C,
+) -> jlong
+where
+    T: Send + 'static,
+    F: Future<Output = Result<T>> + Send + 'static,
+    C: Fn(&mut JNIEnv, &JObject, Result<T>) + Send + 'static,
+{
+    let callback = JavaCallbacks::new(env, callback).unwrap_or_else(|err| throw_error(err));
+    let cancellation = CancellationToken::new();
+    let task = handle::insert(cancellation.clone());
+    let completion = TaskCompletion {
+        callback,
+        complete,
+        task,
+        completed: false,
+        result: PhantomData,
+    };
+    runtime.spawn(async move {
+        let result = tokio::select! {
+            biased;
+            _ = cancellation.cancelled() => Err(cancelled_error("Task")),
+            result = AssertUnwindSafe(future).catch_unwind() => {
+                result.unwrap_or_else(|payload| Err(panic_error(payload)))
+            }
+        };
+        completion.complete(result);
+    });
+    task
+}
+
+/// Completes the Java callback of a spawned task. If the task is dropped before it completes,
+/// which happens to tasks still pending when their runtime is shut down, the callback is
+/// completed with a cancellation error instead.
+struct TaskCompletion<T, C>
+where
+    C: Fn(&mut JNIEnv, &JObject, Result<T>),
+{
+    callback: JavaCallbacks,
+    complete: C,
+    task: jlong,
+    completed: bool,
+    result: PhantomData<fn(Result<T>)>,
+}
+
+impl<T, C> TaskCompletion<T, C>
+where
+    C: Fn(&mut JNIEnv, &JObject, Result<T>),
+{
+    fn complete(mut self, result: Result<T>) {
+        self.completed = true;
+        self.call(result);
+    }
+
+    fn call(&self, result: Result<T>) {
+        let _ = handle::try_remove::<CancellationToken>(self.task);
+        let complete = &self.complete;
+        if let Err(err) = self
+            .callback
+            .call_from_runtime(|env, callback| complete(env, callback, result))
+        {
+            // Complete the callback again with the error so that the Java future does not wait
+            // forever. This has no effect if the future was completed before the failure.
+            let _ = self
+                .callback
+                .call_from_runtime(|env, callback| complete(env, callback, Err(err)));
+        }
+    }
+}
+
+impl<T, C> Drop for TaskCompletion<T, C>
+where
+    C: Fn(&mut JNIEnv, &JObject, Result<T>),
+{
+    fn drop(&mut self) {
+        if !self.completed {
+            self.call(Err(DataFusionError::Execution(
+                "Task was cancelled because its runtime was shut down".to_string(),
+            )));
+        }
+    }
+}
+
+/// Get the error returned when an operation is cancelled from Java
+pub fn cancelled_error(operation: &str) -> DataFusionError {
+    DataFusionError::Execution(format!("{operation} was cancelled"))
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_NativeFuture_cancelTask(
+    env: JNIEnv,
+    _class: JClass,
+    task: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        // The task may have already completed and removed its cancellation token
+        if let Ok(cancellation) = handle::try_get::<CancellationToken>(task) {
+            cancellation.cancel();
+        }
+    })
+}
@@ -1,14 +1,25 @@
 use datafusion::catalog::TableReference;
 use datafusion::datasource::TableProvider;
 use datafusion::execution::context::SessionContext;
-use datafusion::prelude::{CsvReadOptions, ParquetReadOptions, SessionConfig};
+use datafusion::prelude::{AvroReadOptions, SessionConfig};
 use jni::objects::{JClass, JObject, JString};
-use jni::sys::jlong;
+use jni::sys::{jint, jlong};
 use jni::JNIEnv;
-use std::sync::Arc;
-use tokio::runtime::Runtime;
+use std::sync::{Arc, Mutex};
 
-use crate::util::call_error_handler;
+use crate::async_callback::spawn_with_callback;
+use crate::csv_read_options::OwnedCsvReadOptions;
+use crate::exception::{catch_panic, throw_error};
+use crate::handle;
+use crate::json_read_options::OwnedNdJsonReadOptions;
+use crate::parquet_read_options::OwnedParquetReadOptions;
+use crate::runtime::TokioRuntime;
+use crate::runtime_config::OwnedRuntimeConfig;
+use crate::spill_metrics::with_spill_metrics;
+use crate::udaf::create_java_udaf;
+use crate::udf::create_java_udf;
+use crate::udf_library::load_udf_library;
+use crate::util::{call_error_handler, result_exception, set_callback_result};
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_registerCsv(
@@ -18,24 +29,74 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_re
     pointer: jlong,
     name: JString,
     path: JString,
+    options: jlong,
     callback: JObject,
-) {
-    let runtime = unsafe { &mut *(runtime as *mut Runtime) };
-    let name: String = env
-        .get_string(&name)
-        .expect("Couldn't get name as string!")
-        .into();
-    let path: String = env
-        .get_string(&path)
-        .expect("Couldn't get name as string!")
-        .into();
-    let context = unsafe { &mut *(pointer as *mut SessionContext) };
-    runtime.block_on(async {
-        let register_result = context
-            .register_csv(&name, &path, CsvReadOptions::new())
-            .await;
-        call_error_handler(&mut env, callback, register_result);
-    });
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let options = csv_read_options(options);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                context
+                    .register_csv(&name, &path, options.to_read_options())
+                    .await
+            },
+            call_error_handler,
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_readCsv(
+    mut env: JNIEnv,
+    _class: JClass,
+    runtime: jlong,
+    pointer: jlong,
+    path: JString,
+    options: jlong,
+    callback: JObject,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let options = csv_read_options(options);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                context
+                    .read_csv(path.as_str(), options.to_read_options())
+                    .await
+                    .map(handle::insert)
+            },
+            set_callback_result,
+        )
+    })
+}
+
+/// Get CSV read options from a handle, using the default options if the handle is zero
+fn csv_read_options(pointer: jlong) -> OwnedCsvReadOptions {
+    match pointer {
+        0 => OwnedCsvReadOptions::default(),
+        pointer => handle::lock(&handle::get::<Mutex<OwnedCsvReadOptions>>(pointer)).clone(),
+    }
 }
 
 #[no_mangle]
@@ -46,24 +107,218 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_re
     pointer: jlong,
     name: JString,
     path: JString,
+    options: jlong,
     callback: JObject,
-) {
-    let runtime = unsafe { &mut *(runtime as *mut Runtime) };
-    let name: String = env
-        .get_string(&name)
-        .expect("Couldn't get name as string!")
-        .into();
-    let path: String = env
-        .get_string(&path)
-        .expect("Couldn't get path as string!")
-        .into();
-    let context = unsafe { &mut *(pointer as *mut SessionContext) };
-    runtime.block_on(async {
-        let register_result = context
-            .register_parquet(&name, &path, ParquetReadOptions::default())
-            .await;
-        call_error_handler(&mut env, callback, register_result);
-    });
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let options = parquet_read_options(options);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                context
+                    .register_parquet(&name, &path, options.to_read_options())
+                    .await
+            },
+            call_error_handler,
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_readParquet(
+    mut env: JNIEnv,
+    _class: JClass,
+    runtime: jlong,
+    pointer: jlong,
+    path: JString,
+    options: jlong,
+    callback: JObject,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let options = parquet_read_options(options);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                context
+                    .read_parquet(path.as_str(), options.to_read_options())
+                    .await
+                    .map(handle::insert)
+            },
+            set_callback_result,
+        )
+    })
+}
+
+/// Get Parquet read options from a handle, using the default options if the handle is zero
+fn parquet_read_options(pointer: jlong) -> OwnedParquetReadOptions {
+    match pointer {
+        0 => OwnedParquetReadOptions::default(),
+        pointer => handle::lock(&handle::get::<Mutex<OwnedParquetReadOptions>>(pointer)).clone(),
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_registerJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    runtime: jlong,
+    pointer: jlong,
+    name: JString,
+    path: JString,
+    options: jlong,
+    callback: JObject,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let options = json_read_options(options);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                context
+                    .register_json(&name, &path, options.to_read_options())
+                    .await
+            },
+            call_error_handler,
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_readJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    runtime: jlong,
+    pointer: jlong,
+    path: JString,
+    options: jlong,
+    callback: JObject,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let options = json_read_options(options);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                context
+                    .read_json(path.as_str(), options.to_read_options())
+                    .await
+                    .map(handle::insert)
+            },
+            set_callback_result,
+        )
+    })
+}
+
+/// Get JSON read options from a handle, using the default options if the handle is zero
+fn json_read_options(pointer: jlong) -> OwnedNdJsonReadOptions {
+    match pointer {
+        0 => OwnedNdJsonReadOptions::default(),
+        pointer => handle::lock(&handle::get::<Mutex<OwnedNdJsonReadOptions>>(pointer)).clone(),
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_registerAvro(
+    mut env: JNIEnv,
+    _class: JClass,
+    runtime: jlong,
+    pointer: jlong,
+    name: JString,
+    path: JString,
+    callback: JObject,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                context
+                    .register_avro(&name, &path, AvroReadOptions::default())
+                    .await
+            },
+            call_error_handler,
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_readAvro(
+    mut env: JNIEnv,
+    _class: JClass,
+    runtime: jlong,
+    pointer: jlong,
+    path: JString,
+    callback: JObject,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                context
+                    .read_avro(path.as_str(), AvroReadOptions::default())
+                    .await
+                    .map(handle::insert)
+            },
+            set_callback_result,
+        )
+    })
 }
 
 /// Register a table provider as a named table in the session context
@@ -76,21 +331,107 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_re
     pointer: jlong,
     table_reference: JString<'local>,
     table_provider: jlong,
-) -> JString<'local> {
-    let table_reference: String = env
-        .get_string(&table_reference)
-        .expect("Couldn't get table_reference as string!")
-        .into();
-    let context = unsafe { &*(pointer as *const SessionContext) };
-    let table_provider = unsafe { &*(table_provider as *const Arc<dyn TableProvider>) };
-    let table_reference = TableReference::from(table_reference.as_str()).to_owned();
-    let register_result = context.register_table(table_reference, table_provider.clone());
-    let error_message = match register_result {
-        Ok(_) => "".to_string(),
-        Err(err) => err.to_string(),
-    };
-    env.new_string(error_message)
-        .expect("Couldn't create java string!")
+) -> JObject<'local> {
+    catch_panic(env.get_raw(), || {
+        let table_reference: String = env
+            .get_string(&table_reference)
+            .expect("Couldn't get table_reference as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let table_provider = handle::get::<Arc<dyn TableProvider>>(table_provider);
+        let table_reference = TableReference::from(table_reference.as_str()).to_owned();
+        let register_result =
+            context.register_table(table_reference, table_provider.as_ref().clone());
+        result_exception(&mut env, register_result)
+    })
+}
+
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_registerUdf<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pointer: jlong,
+    name: JString<'local>,
+    argument_types: jlong,
+    return_type: jlong,
+    volatility: jint,
+    callbacks: JObject<'local>,
+) -> JObject<'local> {
+    catch_panic(env.get_raw(), || {
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let register_result = create_java_udf(
+            &mut env,
+            &name,
+            argument_types,
+            return_type,
+            volatility,
+            callbacks,
+        )
+        .map(|udf| context.register_udf(udf));
+        result_exception(&mut env, register_result)
+    })
+}
+
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_registerUdaf<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pointer: jlong,
+    name: JString<'local>,
+    argument_types: jlong,
+    return_type: jlong,
+    state_types: jlong,
+    volatility: jint,
+    callbacks: JObject<'local>,
+) -> JObject<'local> {
+    catch_panic(env.get_raw(), || {
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let register_result = create_java_udaf(
+            &mut env,
+            &name,
+            argument_types,
+            return_type,
+            state_types,
+            volatility,
+            callbacks,
+        )
+        .map(|udaf| context.register_udaf(udaf));
+        result_exception(&mut env, register_result)
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_loadUdfLibrary<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pointer: jlong,
+    path: JString<'local>,
+) -> JObject<'local> {
+    catch_panic(env.get_raw(), || {
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        let load_result = load_udf_library(&context, &path);
+        result_exception(&mut env, load_result)
+    })
 }
 
 #[no_mangle]
@@ -101,67 +442,63 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_qu
     pointer: jlong,
     sql: JString,
     callback: JObject,
-) {
-    let runtime = unsafe { &mut *(runtime as *mut Runtime) };
-    let sql: String = env
-        .get_string(&sql)
-        .expect("Couldn't get sql as string!")
-        .into();
-    let context = unsafe { &mut *(pointer as *mut SessionContext) };
-    runtime.block_on(async {
-        let query_result = context.sql(&sql).await;
-        match query_result {
-            Ok(v) => {
-                let err_message = JObject::null();
-                let dataframe = Box::into_raw(Box::new(v)) as jlong;
-                env.call_method(
-                    callback,
-                    "callback",
-                    "(Ljava/lang/String;J)V",
-                    &[(&err_message).into(), dataframe.into()],
-                )
-            }
-            Err(err) => {
-                let err_message = env
-                    .new_string(err.to_string())
-                    .expect("Couldn't create java string!");
-                let dataframe = -1 as jlong;
-                env.call_method(
-                    callback,
-                    "callback",
-                    "(Ljava/lang/String;J)V",
-                    &[(&err_message).into(), dataframe.into()],
-                )
-            }
-        }
-        .expect("failed to call method");
-    });
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let sql: String = env
+            .get_string(&sql)
+            .expect("Couldn't get sql as string!")
+            .into();
+        let context = handle::get::<SessionContext>(pointer);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move { context.sql(&sql).await.map(handle::insert) },
+            set_callback_result,
+        )
+    })
 }
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_SessionContexts_destroySessionContext(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     pointer: jlong,
 ) {
-    let _ = unsafe { Box::from_raw(pointer as *mut SessionContext) };
+    catch_panic(env.get_raw(), || {
+        handle::remove::<SessionContext>(pointer);
+    })
 }
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_SessionContexts_createSessionContext(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
 ) -> jlong {
-    let context = SessionContext::new();
-    Box::into_raw(Box::new(context)) as jlong
+    catch_panic(env.get_raw(), || {
+        let context = SessionContext::with_config(with_spill_metrics(SessionConfig::new()));
+        handle::insert(context)
+    })
 }
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_SessionContexts_createSessionContextWithConfig(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     config: jlong,
+    runtime_config: jlong,
 ) -> jlong {
-    let config = unsafe { &*(config as *const SessionConfig) };
-    let context = SessionContext::with_config(config.clone());
-    Box::into_raw(Box::new(context)) as jlong
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<SessionConfig>>(config);
+        let config = with_spill_metrics(handle::lock(&config).clone());
+        let context = if runtime_config == 0 {
+            SessionContext::with_config(config)
+        } else {
+            let runtime_config = handle::get::<Mutex<OwnedRuntimeConfig>>(runtime_config);
+            let runtime_env = handle::lock(&runtime_config).runtime_env();
+            let runtime_env = runtime_env.unwrap_or_else(|err| throw_error(err));
+            SessionContext::with_config_rt(config, runtime_env)
+        };
+        handle::insert(context)
+    })
 }
@@ -5,22 +5,29 @@ use jni::sys::jlong;
 use jni::JNIEnv;
 use std::sync::Arc;
 
+use crate::exception::catch_panic;
+use crate::handle;
+
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_CsvFormat_create(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
 ) -> jlong {
-    // Return as an Arc<dyn FileFormat> rather than CsvFormat so this
-    // can be passed into ListingOptions.create
-    let format: Arc<dyn FileFormat> = Arc::new(CsvFormat::default());
-    Box::into_raw(Box::new(format)) as jlong
+    catch_panic(env.get_raw(), || {
+        // Return as an Arc<dyn FileFormat> rather than CsvFormat so this
+        // can be passed into ListingOptions.create
+        let format: Arc<dyn FileFormat> = Arc::new(CsvFormat::default());
+        handle::insert(format)
+    })
 }
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_CsvFormat_destroy(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     pointer: jlong,
 ) {
-    let _ = unsafe { Box::from_raw(pointer as *mut Arc<dyn FileFormat>) };
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Arc<dyn FileFormat>>(pointer);
+    })
 }
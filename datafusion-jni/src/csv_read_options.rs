@@ -0,0 +1,166 @@
+use arrow::datatypes::Schema;
+use datafusion::datasource::file_format::file_type::FileCompressionType;
+use datafusion::prelude::CsvReadOptions;
+use jni::objects::{JClass, JObject, JString};
+use jni::sys::{jboolean, jbyte, jint, jlong};
+use jni::JNIEnv;
+use std::sync::Mutex;
+
+use crate::exception::catch_panic;
+use crate::file_compression_type::file_compression_type_from_id;
+use crate::handle;
+use crate::util::{import_schema, result_exception};
+
+/// Owned version of CsvReadOptions, which borrows its file extension and schema
+/// so cannot be stored behind a handle held by Java
+#[derive(Clone)]
+pub struct OwnedCsvReadOptions {
+    has_header: bool,
+    delimiter: u8,
+    schema_infer_max_records: usize,
+    file_extension: String,
+    file_compression_type: FileCompressionType,
+    schema: Option<Schema>,
+}
+
+impl OwnedCsvReadOptions {
+    /// Get CsvReadOptions that borrow from these options
+    pub fn to_read_options(&self) -> CsvReadOptions<'_> {
+        let mut options = CsvReadOptions::new()
+            .has_header(self.has_header)
+            .delimiter(self.delimiter)
+            .schema_infer_max_records(self.schema_infer_max_records)
+            .file_extension(&self.file_extension)
+            .file_compression_type(self.file_compression_type.clone());
+        options.schema = self.schema.as_ref();
+        options
+    }
+}
+
+impl Default for OwnedCsvReadOptions {
+    fn default() -> Self {
+        let defaults = CsvReadOptions::new();
+        Self {
+            has_header: defaults.has_header,
+            delimiter: defaults.delimiter,
+            schema_infer_max_records: defaults.schema_infer_max_records,
+            file_extension: defaults.file_extension.to_string(),
+            file_compression_type: defaults.file_compression_type,
+            schema: None,
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CsvReadOptions_create(
+    env: JNIEnv,
+    _class: JClass,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        handle::insert(Mutex::new(OwnedCsvReadOptions::default()))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CsvReadOptions_destroy(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Mutex<OwnedCsvReadOptions>>(pointer);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CsvReadOptions_setHasHeader(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    has_header: jboolean,
+) {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedCsvReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        options.has_header = has_header != 0u8;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CsvReadOptions_setDelimiter(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    delimiter: jbyte,
+) {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedCsvReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        options.delimiter = delimiter as u8;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CsvReadOptions_setSchemaInferMaxRecords(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    max_records: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedCsvReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        options.schema_infer_max_records = max_records as usize;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CsvReadOptions_setFileExtension(
+    mut env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    file_extension: JString,
+) {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedCsvReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        let file_extension: String = env
+            .get_string(&file_extension)
+            .expect("Couldn't get file_extension as string!")
+            .into();
+        options.file_extension = file_extension;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CsvReadOptions_setFileCompressionType<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pointer: jlong,
+    compression_type_id: jint,
+) -> JObject<'local> {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedCsvReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        let result = file_compression_type_from_id(compression_type_id)
+            .map(|compression_type| options.file_compression_type = compression_type);
+        result_exception(&mut env, result)
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CsvReadOptions_setSchema<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pointer: jlong,
+    schema: jlong,
+) -> JObject<'local> {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedCsvReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        let result = import_schema(schema).map(|schema| options.schema = Some(schema));
+        result_exception(&mut env, result)
+    })
+}
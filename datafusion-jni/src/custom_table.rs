@@ -0,0 +1,297 @@
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::ffi::FFI_ArrowSchema;
+use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use async_trait::async_trait;
+use datafusion::datasource::{TableProvider, TableType};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::context::{SessionState, TaskContext};
+use datafusion::logical_expr::{Expr, TableProviderFilterPushDown};
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::{
+    DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream, Statistics,
+};
+use jni::objects::{JClass, JObject, JValue};
+use jni::sys::{jint, jlong};
+use jni::JNIEnv;
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::exception::catch_panic;
+use crate::handle;
+use crate::java_callbacks::JavaCallbacks;
+use crate::stream_table::JavaStreamReader;
+use crate::util::{java_call_error, jni_error, set_object_result};
+
+/// Reference to the Java TableProviderCallbacks object that implements a table
+struct JavaTableCallbacks(JavaCallbacks);
+
+impl JavaTableCallbacks {
+    fn schema(&self) -> Result<Schema> {
+        self.0.call(|env, callbacks| {
+            let mut ffi_schema = FFI_ArrowSchema::empty();
+            let address = &mut ffi_schema as *mut FFI_ArrowSchema as jlong;
+            env.call_method(callbacks, "exportSchema", "(J)V", &[JValue::Long(address)])
+                .map_err(|err| java_call_error(env, err))?;
+            Schema::try_from(&ffi_schema).map_err(DataFusionError::from)
+        })
+    }
+
+    fn scan(
+        &self,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<ArrowArrayStreamReader> {
+        self.0.call(|env, callbacks| {
+            let projection = match projection {
+                Some(projection) => {
+                    let indices: Vec<jint> =
+                        projection.iter().map(|&index| index as jint).collect();
+                    let array = env.new_int_array(indices.len() as i32).map_err(jni_error)?;
+                    env.set_int_array_region(&array, 0, &indices)
+                        .map_err(jni_error)?;
+                    JObject::from(array)
+                }
+                None => JObject::null(),
+            };
+            // Filters are passed as new expression handles that are owned by Java
+            let filter_pointers: Vec<jlong> = filters
+                .iter()
+                .map(|filter| handle::insert(filter.clone()))
+                .collect();
+            let result = call_scan(env, callbacks, &projection, &filter_pointers, limit);
+            if result.is_err() {
+                // Java may not have taken ownership of the filters if the call failed
+                remove_filters(&filter_pointers);
+            }
+            result
+        })
+    }
+
+    fn supports_filter_pushdown(&self, filter: &Expr) -> Result<TableProviderFilterPushDown> {
+        self.0.call(|env, callbacks| {
+            // The filter is passed as a new expression handle that is owned by Java
+            let filter = handle::insert(filter.clone());
+            let pushdown = env
+                .call_method(
+                    callbacks,
+                    "supportsFilterPushDown",
+                    "(J)I",
+                    &[JValue::Long(filter)],
+                )
+                .and_then(|pushdown| pushdown.i())
+                .map_err(|err| {
+                    remove_filters(&[filter]);
+                    java_call_error(env, err)
+                })?;
+            match pushdown {
+                0 => Ok(TableProviderFilterPushDown::Unsupported),
+                1 => Ok(TableProviderFilterPushDown::Inexact),
+                2 => Ok(TableProviderFilterPushDown::Exact),
+                _ => Err(DataFusionError::Execution(format!(
+                    "Invalid filter push down id {pushdown}"
+                ))),
+            }
+        })
+    }
+}
+
+/// Call the Java scan method with filters that have already been converted to handles
+fn call_scan(
+    env: &mut JNIEnv,
+    callbacks: &JObject,
+    projection: &JObject,
+    filter_pointers: &[jlong],
+    limit: Option<usize>,
+) -> Result<ArrowArrayStreamReader> {
+    let filters = env
+        .new_long_array(filter_pointers.len() as i32)
+        .map_err(jni_error)?;
+    env.set_long_array_region(&filters, 0, filter_pointers)
+        .map_err(jni_error)?;
+    let filters = JObject::from(filters);
+    let limit = limit.map(|limit| limit as jlong).unwrap_or(-1);
+    let mut stream = FFI_ArrowArrayStream::empty();
+    let address = &mut stream as *mut FFI_ArrowArrayStream;
+    env.call_method(
+        callbacks,
+        "scan",
+        "([I[JJJ)V",
+        &[
+            JValue::Object(projection),
+            JValue::Object(&filters),
+            JValue::Long(limit),
+            JValue::Long(address as jlong),
+        ],
+    )
+    .map_err(|err| java_call_error(env, err))?;
+    unsafe { ArrowArrayStreamReader::from_raw(address) }.map_err(DataFusionError::from)
+}
+
+/// Remove filter expression handles that were not closed by Java.
+/// Handles that Java has already closed are ignored.
+fn remove_filters(filter_pointers: &[jlong]) {
+    for &filter in filter_pointers {
+        let _ = handle::try_remove::<Expr>(filter);
+    }
+}
+
+/// A TableProvider that calls back into Java to get the table schema and scan data
+struct JavaTableProvider {
+    callbacks: Arc<JavaTableCallbacks>,
+    schema: SchemaRef,
+}
+
+#[async_trait]
+impl TableProvider for JavaTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let schema = match projection {
+            Some(projection) => Arc::new(self.schema.project(projection)?),
+            None => self.schema.clone(),
+        };
+        Ok(Arc::new(JavaScanExec {
+            callbacks: self.callbacks.clone(),
+            schema,
+            projection: projection.cloned(),
+            filters: filters.to_vec(),
+            limit,
+        }))
+    }
+
+    fn supports_filter_pushdown(&self, filter: &Expr) -> Result<TableProviderFilterPushDown> {
+        self.callbacks.supports_filter_pushdown(filter)
+    }
+}
+
+/// Execution plan that reads a single partition of data from a Java table scan
+struct JavaScanExec {
+    callbacks: Arc<JavaTableCallbacks>,
+    schema: SchemaRef,
+    projection: Option<Vec<usize>>,
+    filters: Vec<Expr>,
+    limit: Option<usize>,
+}
+
+impl Debug for JavaScanExec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JavaScanExec")
+            .field("schema", &self.schema)
+            .field("projection", &self.projection)
+            .field("filters", &self.filters)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl ExecutionPlan for JavaScanExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Internal(format!(
+                "Invalid partition {partition} for JavaScanExec"
+            )));
+        }
+        let reader = self
+            .callbacks
+            .scan(self.projection.as_ref(), &self.filters, self.limit)?;
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            futures::stream::iter(JavaStreamReader(reader)),
+        )))
+    }
+
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "JavaScanExec: projection={:?}, filters={:?}, limit={:?}",
+            self.projection, self.filters, self.limit
+        )
+    }
+
+    fn statistics(&self) -> Statistics {
+        Statistics::default()
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CustomTable_create(
+    mut env: JNIEnv,
+    _class: JClass,
+    callbacks: JObject,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let callbacks = JavaCallbacks::new(&mut env, callbacks).map(JavaTableCallbacks);
+        let table_provider_result = callbacks.and_then(|callbacks| {
+            let schema = callbacks.schema()?;
+            // Return as an Arc<dyn TableProvider> so this can be passed into SessionContext.registerTable
+            let table_provider: Arc<dyn TableProvider> = Arc::new(JavaTableProvider {
+                callbacks: Arc::new(callbacks),
+                schema: Arc::new(schema),
+            });
+            Ok(handle::insert(table_provider))
+        });
+        set_object_result(&mut env, object_result, table_provider_result);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_CustomTable_destroy(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Arc<dyn TableProvider>>(pointer);
+    })
+}
@@ -1,16 +1,34 @@
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::ffi::FFI_ArrowSchema;
 use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+use arrow::util::pretty;
+use datafusion::common::DFSchema;
 use datafusion::dataframe::DataFrame;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::type_coercion::binary::comparison_coercion;
 use datafusion::prelude::SessionContext;
-use jni::objects::{JClass, JObject, JString};
-use jni::sys::jlong;
+use futures::TryStreamExt;
+use jni::objects::{JClass, JLongArray, JObject, JObjectArray, JString};
+use jni::sys::{jboolean, jint, jlong};
 use jni::JNIEnv;
 use std::convert::Into;
 use std::io::BufWriter;
 use std::io::Cursor;
-use std::sync::Arc;
-use tokio::runtime::Runtime;
+use std::ptr::addr_of_mut;
 
-use crate::util::{call_error_handler, set_callback_result};
+use crate::async_callback::spawn_with_callback;
+use crate::exception::{catch_panic, create_exception};
+use crate::expr::{expr_from_pointer, exprs_from_pointers};
+use crate::handle;
+use crate::join_type::join_type_from_id;
+use crate::runtime::TokioRuntime;
+use crate::spill_metrics::{execute_recording_spills, plan_recording_spills};
+use crate::stream::SharedRecordBatchStream;
+use crate::util::{
+    call_error_handler, set_callback_result, set_callback_result_error, set_callback_result_ok,
+    set_object_result, string_array,
+};
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_collectDataframe(
@@ -19,55 +37,50 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_collectDatafr
     runtime: jlong,
     dataframe: jlong,
     callback: JObject,
-) {
-    let runtime = unsafe { &mut *(runtime as *mut Runtime) };
-    let dataframe = unsafe { &*(dataframe as *const DataFrame) };
-    let schema = dataframe.schema().into();
-    runtime.block_on(async {
-        let batches = dataframe.clone().collect().await;
-        let batches = match batches {
-            Ok(batches) => batches,
-            Err(err) => {
-                let err_message = env
-                    .new_string(err.to_string())
-                    .expect("Couldn't create java string for error message");
-                let buff = Cursor::new(vec![0; 0]);
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                let schema = dataframe.schema().into();
+                let (stream, _plan) = execute_recording_spills(dataframe.as_ref().clone()).await?;
+                let batches: Vec<RecordBatch> = stream.try_collect().await?;
+                let mut buff = Cursor::new(vec![0; 0]);
+                {
+                    let mut writer = FileWriter::try_new(BufWriter::new(&mut buff), &schema)?;
+                    for batch in batches {
+                        writer.write(&batch)?;
+                    }
+                    writer.finish()?;
+                }
+                Ok(buff.into_inner())
+            },
+            |env, callback, result| {
+                let (exception, bytes) = match result {
+                    Ok(bytes) => (JObject::null(), bytes),
+                    Err(err) => {
+                        let exception =
+                            create_exception(env, &err).expect("Couldn't create java exception");
+                        (JObject::from(exception), Vec::new())
+                    }
+                };
                 let ba = env
-                    .byte_array_from_slice(&buff.get_ref())
-                    .expect("cannot create empty byte array");
+                    .byte_array_from_slice(&bytes)
+                    .expect("cannot create byte array");
                 env.call_method(
                     callback,
                     "accept",
                     "(Ljava/lang/Object;Ljava/lang/Object;)V",
-                    &[(&err_message).into(), (&ba).into()],
+                    &[(&exception).into(), (&ba).into()],
                 )
                 .expect("failed to call method");
-                return;
-            }
-        };
-        let mut buff = Cursor::new(vec![0; 0]);
-        {
-            let mut writer = FileWriter::try_new(BufWriter::new(&mut buff), &schema)
-                .expect("failed to create writer");
-            for batch in batches {
-                writer.write(&batch).expect("failed to write batch");
-            }
-            writer.finish().expect("failed to finish");
-        }
-        let err_message = env
-            .new_string("".to_string())
-            .expect("Couldn't create java string!");
-        let ba = env
-            .byte_array_from_slice(&buff.get_ref())
-            .expect("cannot create byte array");
-        env.call_method(
-            callback,
-            "accept",
-            "(Ljava/lang/Object;Ljava/lang/Object;)V",
-            &[(&err_message).into(), (&ba).into()],
+            },
         )
-        .expect("failed to call method");
-    });
+    })
 }
 
 #[no_mangle]
@@ -77,17 +90,24 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_executeStream
     runtime: jlong,
     dataframe: jlong,
     callback: JObject,
-) {
-    let runtime = unsafe { &*(runtime as *const Runtime) };
-    let dataframe = unsafe { &*(dataframe as *const DataFrame) };
-    runtime.block_on(async {
-        let stream_result = dataframe.clone().execute_stream().await;
-        set_callback_result(
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        spawn_with_callback(
             &mut env,
+            &runtime,
             callback,
-            stream_result.map(|stream| Box::into_raw(Box::new(stream))),
-        );
-    });
+            async move {
+                execute_recording_spills(dataframe.as_ref().clone())
+                    .await
+                    .map(|(stream, plan)| {
+                        handle::insert(SharedRecordBatchStream::new(stream, plan))
+                    })
+            },
+            set_callback_result,
+        )
+    })
 }
 
 #[no_mangle]
@@ -97,13 +117,22 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_showDataframe
     runtime: jlong,
     dataframe: jlong,
     callback: JObject,
-) {
-    let runtime = unsafe { &mut *(runtime as *mut Runtime) };
-    let dataframe = unsafe { &*(dataframe as *const DataFrame) };
-    runtime.block_on(async {
-        let result = dataframe.clone().show().await;
-        call_error_handler(&mut env, callback, result);
-    });
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                let (stream, _plan) = execute_recording_spills(dataframe.as_ref().clone()).await?;
+                let batches: Vec<RecordBatch> = stream.try_collect().await?;
+                Ok(pretty::print_batches(&batches)?)
+            },
+            call_error_handler,
+        )
+    })
 }
 
 #[no_mangle]
@@ -114,17 +143,27 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_writeParquet(
     dataframe: jlong,
     path: JString,
     callback: JObject,
-) {
-    let runtime = unsafe { &mut *(runtime as *mut Runtime) };
-    let dataframe = unsafe { &*(dataframe as *const DataFrame) };
-    let path: String = env
-        .get_string(&path)
-        .expect("Couldn't get path as string!")
-        .into();
-    runtime.block_on(async {
-        let result = dataframe.clone().write_parquet(&path, None).await;
-        call_error_handler(&mut env, callback, result);
-    });
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                let (state, plan) = plan_recording_spills(dataframe.as_ref().clone()).await?;
+                SessionContext::with_state(state)
+                    .write_parquet(plan.plan(), &path, None)
+                    .await
+            },
+            call_error_handler,
+        )
+    })
 }
 
 #[no_mangle]
@@ -135,17 +174,27 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_writeCsv(
     dataframe: jlong,
     path: JString,
     callback: JObject,
-) {
-    let runtime = unsafe { &mut *(runtime as *mut Runtime) };
-    let dataframe = unsafe { &*(dataframe as *const DataFrame) };
-    let path: String = env
-        .get_string(&path)
-        .expect("Couldn't get path as string!")
-        .into();
-    runtime.block_on(async {
-        let result = dataframe.clone().write_csv(&path).await;
-        call_error_handler(&mut env, callback, result);
-    });
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let path: String = env
+            .get_string(&path)
+            .expect("Couldn't get path as string!")
+            .into();
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                let (state, plan) = plan_recording_spills(dataframe.as_ref().clone()).await?;
+                SessionContext::with_state(state)
+                    .write_csv(plan.plan(), &path)
+                    .await
+            },
+            call_error_handler,
+        )
+    })
 }
 
 #[no_mangle]
@@ -157,27 +206,343 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_registerTable
     session: jlong,
     name: JString,
     callback: JObject,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let context = handle::get::<SessionContext>(session);
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move {
+                context
+                    .register_table(name.as_str(), dataframe.as_ref().clone().into_view())
+                    .map(|_| ())
+            },
+            call_error_handler,
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_selectColumns(
+    mut env: JNIEnv,
+    _class: JClass,
+    dataframe: jlong,
+    columns: JObjectArray,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let column_names = string_array(&mut env, &columns);
+        let column_names: Vec<&str> = column_names.iter().map(|name| name.as_str()).collect();
+        let result = dataframe.as_ref().clone().select_columns(&column_names);
+        set_object_result(&mut env, object_result, result.map(handle::insert));
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_select(
+    mut env: JNIEnv,
+    _class: JClass,
+    dataframe: jlong,
+    exprs: JLongArray,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let exprs = exprs_from_pointers(&mut env, &exprs);
+        let result = dataframe.as_ref().clone().select(exprs);
+        set_object_result(&mut env, object_result, result.map(handle::insert));
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_filter(
+    mut env: JNIEnv,
+    _class: JClass,
+    dataframe: jlong,
+    predicate: jlong,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let predicate = expr_from_pointer(predicate);
+        let result = dataframe.as_ref().clone().filter(predicate);
+        set_object_result(&mut env, object_result, result.map(handle::insert));
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_aggregate(
+    mut env: JNIEnv,
+    _class: JClass,
+    dataframe: jlong,
+    group_exprs: JLongArray,
+    aggregate_exprs: JLongArray,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let group_exprs = exprs_from_pointers(&mut env, &group_exprs);
+        let aggregate_exprs = exprs_from_pointers(&mut env, &aggregate_exprs);
+        let result = dataframe
+            .as_ref()
+            .clone()
+            .aggregate(group_exprs, aggregate_exprs);
+        set_object_result(&mut env, object_result, result.map(handle::insert));
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_sort(
+    mut env: JNIEnv,
+    _class: JClass,
+    dataframe: jlong,
+    sort_exprs: JLongArray,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let sort_exprs = exprs_from_pointers(&mut env, &sort_exprs);
+        let result = dataframe.as_ref().clone().sort(sort_exprs);
+        set_object_result(&mut env, object_result, result.map(handle::insert));
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_limit(
+    mut env: JNIEnv,
+    _class: JClass,
+    dataframe: jlong,
+    skip: jlong,
+    has_fetch: jboolean,
+    fetch: jlong,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let result = limit_count(skip, "skip").and_then(|skip| {
+            let fetch = if has_fetch == 1u8 {
+                Some(limit_count(fetch, "fetch")?)
+            } else {
+                None
+            };
+            dataframe.as_ref().clone().limit(skip, fetch)
+        });
+        set_object_result(&mut env, object_result, result.map(handle::insert));
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_distinct(
+    mut env: JNIEnv,
+    _class: JClass,
+    dataframe: jlong,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let result = dataframe.as_ref().clone().distinct();
+        set_object_result(&mut env, object_result, result.map(handle::insert));
+    })
+}
+
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_join(
+    mut env: JNIEnv,
+    _class: JClass,
+    left: jlong,
+    right: jlong,
+    join_type: jint,
+    left_columns: JObjectArray,
+    right_columns: JObjectArray,
+    filter: jlong,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let left = handle::get::<DataFrame>(left);
+        let right = handle::get::<DataFrame>(right);
+        let left_columns = string_array(&mut env, &left_columns);
+        let left_columns: Vec<&str> = left_columns.iter().map(|name| name.as_str()).collect();
+        let right_columns = string_array(&mut env, &right_columns);
+        let right_columns: Vec<&str> = right_columns.iter().map(|name| name.as_str()).collect();
+        let filter = if filter == 0 {
+            None
+        } else {
+            Some(expr_from_pointer(filter))
+        };
+        let result = join_type_from_id(join_type).and_then(|join_type| {
+            left.as_ref().clone().join(
+                right.as_ref().clone(),
+                join_type,
+                &left_columns,
+                &right_columns,
+                filter,
+            )
+        });
+        set_object_result(&mut env, object_result, result.map(handle::insert));
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_joinOn(
+    mut env: JNIEnv,
+    _class: JClass,
+    left: jlong,
+    right: jlong,
+    join_type: jint,
+    on_exprs: JLongArray,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let left = handle::get::<DataFrame>(left);
+        let right = handle::get::<DataFrame>(right);
+        let on_exprs = exprs_from_pointers(&mut env, &on_exprs);
+        let result = join_type_from_id(join_type).and_then(|join_type| {
+            left.as_ref()
+                .clone()
+                .join_on(right.as_ref().clone(), join_type, on_exprs)
+        });
+        set_object_result(&mut env, object_result, result.map(handle::insert));
+    })
+}
+
+/// Field metadata key used to pass the table qualifier of a column to Java
+const QUALIFIER_METADATA_KEY: &str = "datafusion.qualifier";
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_getSchema(
+    mut env: JNIEnv,
+    _class: JClass,
+    dataframe: jlong,
+    callback: JObject,
 ) {
-    let runtime = unsafe { &mut *(runtime as *mut Runtime) };
-    let dataframe = unsafe { &*(dataframe as *const DataFrame) };
-    let context = unsafe { &mut *(session as *mut SessionContext) };
-    let name: String = env
-        .get_string(&name)
-        .expect("Couldn't get name as string!")
-        .into();
-    runtime.block_on(async {
-        let result = context
-            .register_table(name.as_str(), dataframe.clone().into_view())
-            .map(|_| ());
-        call_error_handler(&mut env, callback, result);
-    });
+    catch_panic(env.get_raw(), || {
+        let dataframe = handle::get::<DataFrame>(dataframe);
+        let schema = arrow_schema(dataframe.schema());
+        match FFI_ArrowSchema::try_from(&schema) {
+            Ok(mut ffi_schema) => {
+                set_callback_result_ok(&mut env, &callback, addr_of_mut!(ffi_schema) as jlong);
+            }
+            Err(err) => {
+                set_callback_result_error(&mut env, &callback, &err);
+            }
+        }
+    })
+}
+
+/// Convert a DFSchema into an Arrow schema, keeping the table qualifier of each field
+/// as field metadata
+fn arrow_schema(schema: &DFSchema) -> Schema {
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|field| match field.qualifier() {
+            Some(qualifier) => {
+                let mut metadata = field.field().metadata().clone();
+                metadata.insert(QUALIFIER_METADATA_KEY.to_string(), qualifier.to_string());
+                field.field().as_ref().clone().with_metadata(metadata)
+            }
+            None => field.field().as_ref().clone(),
+        })
+        .collect();
+    Schema::new_with_metadata(fields, schema.metadata().clone())
+}
+
+macro_rules! set_operation {
+    ($name:ident, $method:ident) => {
+        #[no_mangle]
+        pub extern "system" fn $name(
+            mut env: JNIEnv,
+            _class: JClass,
+            left: jlong,
+            right: jlong,
+            object_result: JObject,
+        ) {
+            catch_panic(env.get_raw(), || {
+                let left = handle::get::<DataFrame>(left);
+                let right = handle::get::<DataFrame>(right);
+                let result = check_union_compatible(&left, &right)
+                    .and_then(|_| left.as_ref().clone().$method(right.as_ref().clone()));
+                set_object_result(&mut env, object_result, result.map(handle::insert));
+            })
+        }
+    };
+}
+
+set_operation!(Java_org_apache_arrow_datafusion_DataFrames_union, union);
+set_operation!(
+    Java_org_apache_arrow_datafusion_DataFrames_unionDistinct,
+    union_distinct
+);
+set_operation!(
+    Java_org_apache_arrow_datafusion_DataFrames_intersect,
+    intersect
+);
+set_operation!(Java_org_apache_arrow_datafusion_DataFrames_except, except);
+
+/// Check that two DataFrames have the same number of columns with compatible data types,
+/// so that their rows can be combined by a set operation
+fn check_union_compatible(left: &DataFrame, right: &DataFrame) -> Result<()> {
+    let left_fields = left.schema().fields();
+    let right_fields = right.schema().fields();
+    if left_fields.len() != right_fields.len() {
+        return Err(DataFusionError::Plan(format!(
+            "Set operations require DataFrames with the same number of columns, \
+            but the left has {} and the right has {}",
+            left_fields.len(),
+            right_fields.len()
+        )));
+    }
+    for (left_field, right_field) in left_fields.iter().zip(right_fields) {
+        if !set_operation_coercible(left_field.data_type(), right_field.data_type()) {
+            return Err(DataFusionError::Plan(format!(
+                "Set operations require matching column types, but column {} has type {} \
+                on the left and column {} has type {} on the right",
+                left_field.name(),
+                left_field.data_type(),
+                right_field.name(),
+                right_field.data_type()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Whether columns of two types can be combined by a set operation.
+/// DataFusion also coerces between strings and numbers, which is not allowed here,
+/// so the types must be the same kind of type as well as coercible.
+fn set_operation_coercible(left: &DataType, right: &DataType) -> bool {
+    let is_string =
+        |data_type: &DataType| matches!(data_type, DataType::Utf8 | DataType::LargeUtf8);
+    let same_kind = left == right
+        || *left == DataType::Null
+        || *right == DataType::Null
+        || (left.is_numeric() && right.is_numeric())
+        || (is_string(left) && is_string(right));
+    same_kind && comparison_coercion(left, right).is_some()
 }
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_DataFrames_destroyDataFrame(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     pointer: jlong,
 ) {
-    let _ = unsafe { Box::from_raw(pointer as *mut Arc<DataFrame>) };
+    catch_panic(env.get_raw(), || {
+        handle::remove::<DataFrame>(pointer);
+    })
+}
+
+/// Convert a row count for a limit, which cannot be negative
+fn limit_count(count: jlong, name: &str) -> Result<usize> {
+    usize::try_from(count)
+        .map_err(|_| DataFusionError::Plan(format!("Limit {name} cannot be negative: {count}")))
 }
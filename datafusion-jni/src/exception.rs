@@ -0,0 +1,144 @@
+use std::any::Any;
+use std::error::Error;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::OnceLock;
+
+use arrow::error::ArrowError;
+use datafusion::error::DataFusionError;
+use jni::objects::{GlobalRef, JClass, JObject, JThrowable, JValue};
+use jni::sys::jint;
+use jni::JNIEnv;
+
+const EXCEPTION_CLASS_NAME: &str = "org/apache/arrow/datafusion/DataFusionException";
+
+/// Signature of the static DataFusionException.create method
+const CREATE_EXCEPTION_SIGNATURE: &str =
+    "(ILjava/lang/String;Ljava/lang/Throwable;)Lorg/apache/arrow/datafusion/DataFusionException;";
+
+static EXCEPTION_CLASS: OnceLock<GlobalRef> = OnceLock::new();
+
+/// Create a Java DataFusionException for an error.
+/// The chain of error sources is converted into the chain of exception causes.
+pub fn create_exception<'local>(
+    env: &mut JNIEnv<'local>,
+    error: &(dyn Error + 'static),
+) -> jni::errors::Result<JThrowable<'local>> {
+    let cause = match error.source() {
+        Some(source) => create_exception(env, source)?,
+        None => JThrowable::from(JObject::null()),
+    };
+    let message = env.new_string(error.to_string())?;
+    let args = [
+        JValue::Int(error_kind_id(error)),
+        JValue::Object(&message),
+        JValue::Object(&cause),
+    ];
+    let exception = match EXCEPTION_CLASS.get() {
+        Some(class) => {
+            let class: &JClass = class.as_obj().into();
+            env.call_static_method(class, "create", CREATE_EXCEPTION_SIGNATURE, &args)
+        }
+        None => env.call_static_method(
+            EXCEPTION_CLASS_NAME,
+            "create",
+            CREATE_EXCEPTION_SIGNATURE,
+            &args,
+        ),
+    }?
+    .l()?;
+    Ok(JThrowable::from(exception))
+}
+
+/// Look up and cache the DataFusionException class. This is done when the library is loaded,
+/// as looking up a class by name from a native thread uses the system class loader,
+/// which may not be able to find it.
+pub fn cache_exception_class(env: &mut JNIEnv) -> jni::errors::Result<()> {
+    let class = env.find_class(EXCEPTION_CLASS_NAME)?;
+    let class = env.new_global_ref(class)?;
+    let _ = EXCEPTION_CLASS.set(class);
+    Ok(())
+}
+
+/// Run the body of a JNI function, converting a panic into a thrown Java exception rather than
+/// letting it unwind into the JVM, which would abort the process.
+/// If a Java exception is already pending, for example because a callback threw,
+/// that exception is left to propagate. After a panic the default value is returned,
+/// which Java ignores as an exception is thrown.
+pub fn catch_panic<R: Default>(env: *mut jni::sys::JNIEnv, body: impl FnOnce() -> R) -> R {
+    match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(result) => result,
+        Err(payload) => {
+            if let Ok(mut env) = unsafe { JNIEnv::from_raw(env) } {
+                throw_exception(&mut env, &panic_error(payload));
+            }
+            R::default()
+        }
+    }
+}
+
+/// Stop the current JNI call and throw a Java exception for an error.
+/// This unwinds to the enclosing catch_panic without being reported as a panic.
+pub fn throw_error(error: DataFusionError) -> ! {
+    resume_unwind(Box::new(error))
+}
+
+/// Get the error to report to Java for a caught panic, which is either an error
+/// raised with throw_error or an internal error describing the panic
+pub fn panic_error(payload: Box<dyn Any + Send>) -> DataFusionError {
+    let payload = match payload.downcast::<DataFusionError>() {
+        Ok(error) => return *error,
+        Err(payload) => payload,
+    };
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    };
+    DataFusionError::Internal(format!("Native code panicked: {message}"))
+}
+
+fn throw_exception(env: &mut JNIEnv, error: &DataFusionError) {
+    if env.exception_check().unwrap_or(false) {
+        return;
+    }
+    let thrown = match create_exception(env, error) {
+        Ok(exception) => env.throw(exception),
+        // Creating the exception failed with its own Java exception, which is left pending
+        Err(_) if env.exception_check().unwrap_or(false) => Ok(()),
+        Err(_) => env.throw_new("java/lang/RuntimeException", error.to_string()),
+    };
+    // The JVM is unusable if even an exception cannot be thrown
+    if thrown.is_err() {
+        env.fatal_error(format!("Failed to throw Java exception: {error}"));
+    }
+}
+
+/// Get the id of the Java ErrorKind for an error
+fn error_kind_id(error: &(dyn Error + 'static)) -> jint {
+    if let Some(error) = error.downcast_ref::<DataFusionError>() {
+        match error {
+            DataFusionError::Plan(_) => 0,
+            DataFusionError::SQL(_) => 1,
+            DataFusionError::SchemaError(_) => 2,
+            DataFusionError::Execution(_) => 3,
+            DataFusionError::ResourcesExhausted(_) => 4,
+            DataFusionError::IoError(_) => 5,
+            DataFusionError::ArrowError(_) => 6,
+            DataFusionError::NotImplemented(_) => 7,
+            DataFusionError::ObjectStore(_) => 8,
+            DataFusionError::ParquetError(_) => 9,
+            DataFusionError::AvroError(_) => 10,
+            DataFusionError::Internal(_) => 12,
+            DataFusionError::Context(_, inner) => error_kind_id(inner.as_ref()),
+            _ => 11,
+        }
+    } else if error.is::<ArrowError>() {
+        6
+    } else if error.is::<std::io::Error>() {
+        5
+    } else {
+        11
+    }
+}
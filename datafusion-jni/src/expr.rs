@@ -0,0 +1,471 @@
+use arrow::datatypes::{DataType, DECIMAL128_MAX_PRECISION};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::expr::ScalarFunction;
+use datafusion::logical_expr::{
+    avg, binary_expr, cast, col, count, count_distinct, max, min, sum, BuiltinScalarFunction, Case,
+    Expr, Operator,
+};
+use datafusion::scalar::ScalarValue;
+use jni::objects::{JByteArray, JClass, JLongArray, JObject, JString};
+use jni::sys::{jboolean, jbyte, jdouble, jfloat, jint, jlong, jshort};
+use jni::JNIEnv;
+use std::str::FromStr;
+
+use crate::exception::catch_panic;
+use crate::handle;
+use crate::util::{import_schema, set_object_result};
+
+/// Get copies of the expressions referenced by an array of expression handles
+pub fn exprs_from_pointers(env: &mut JNIEnv, pointers: &JLongArray) -> Vec<Expr> {
+    let length = env
+        .get_array_length(pointers)
+        .expect("Couldn't get array length of expression pointers");
+    let mut addresses = vec![0; length as usize];
+    env.get_long_array_region(pointers, 0, &mut addresses)
+        .expect("Couldn't get expression pointers");
+    addresses.into_iter().map(expr_from_pointer).collect()
+}
+
+/// Get a copy of the expression referenced by an expression handle
+pub fn expr_from_pointer(pointer: jlong) -> Expr {
+    handle::get::<Expr>(pointer).as_ref().clone()
+}
+
+fn into_pointer(expr: Expr) -> jlong {
+    handle::insert(expr)
+}
+
+/// Get an optional expression from a handle that may be zero
+fn optional_expr(pointer: jlong) -> Option<Box<Expr>> {
+    match pointer {
+        0 => None,
+        pointer => Some(Box::new(expr_from_pointer(pointer))),
+    }
+}
+
+/// Get the data type of the single field in a schema exported from Java
+fn import_data_type(schema: jlong) -> Result<DataType> {
+    let schema = import_schema(schema)?;
+    match schema.fields().first() {
+        Some(field) => Ok(field.data_type().clone()),
+        None => Err(DataFusionError::Plan(
+            "Expected a schema with a single field".to_string(),
+        )),
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_destroy(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Expr>(pointer);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_display<'local>(
+    env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pointer: jlong,
+) -> JString<'local> {
+    catch_panic(env.get_raw(), || {
+        let expr = expr_from_pointer(pointer);
+        env.new_string(expr.to_string())
+            .expect("Couldn't create java string!")
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_column(
+    mut env: JNIEnv,
+    _class: JClass,
+    name: JString,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        into_pointer(col(name))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_alias(
+    mut env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    name: JString,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        into_pointer(expr_from_pointer(pointer).alias(name))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_sort(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    ascending: jboolean,
+    nulls_first: jboolean,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let expr = expr_from_pointer(pointer);
+        into_pointer(expr.sort(ascending != 0u8, nulls_first != 0u8))
+    })
+}
+
+// Aggregate functions
+
+macro_rules! aggregate_function {
+    ($name:ident, $function:ident) => {
+        #[no_mangle]
+        pub extern "system" fn $name(env: JNIEnv, _class: JClass, pointer: jlong) -> jlong {
+            catch_panic(env.get_raw(), || {
+                into_pointer($function(expr_from_pointer(pointer)))
+            })
+        }
+    };
+}
+
+aggregate_function!(Java_org_apache_arrow_datafusion_Expr_count, count);
+aggregate_function!(
+    Java_org_apache_arrow_datafusion_Expr_countDistinct,
+    count_distinct
+);
+aggregate_function!(Java_org_apache_arrow_datafusion_Expr_sum, sum);
+aggregate_function!(Java_org_apache_arrow_datafusion_Expr_avg, avg);
+aggregate_function!(Java_org_apache_arrow_datafusion_Expr_min, min);
+aggregate_function!(Java_org_apache_arrow_datafusion_Expr_max, max);
+
+// Literals
+
+macro_rules! literal {
+    ($name:ident, $value_type:ty, $scalar:ident) => {
+        #[no_mangle]
+        pub extern "system" fn $name(env: JNIEnv, _class: JClass, value: $value_type) -> jlong {
+            catch_panic(env.get_raw(), || {
+                into_pointer(Expr::Literal(ScalarValue::$scalar(Some(value))))
+            })
+        }
+    };
+}
+
+literal!(Java_org_apache_arrow_datafusion_Expr_litInt8, jbyte, Int8);
+literal!(
+    Java_org_apache_arrow_datafusion_Expr_litInt16,
+    jshort,
+    Int16
+);
+literal!(Java_org_apache_arrow_datafusion_Expr_litInt32, jint, Int32);
+literal!(Java_org_apache_arrow_datafusion_Expr_litInt64, jlong, Int64);
+literal!(
+    Java_org_apache_arrow_datafusion_Expr_litFloat32,
+    jfloat,
+    Float32
+);
+literal!(
+    Java_org_apache_arrow_datafusion_Expr_litFloat64,
+    jdouble,
+    Float64
+);
+literal!(
+    Java_org_apache_arrow_datafusion_Expr_litDate32,
+    jint,
+    Date32
+);
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_nullLiteral(
+    env: JNIEnv,
+    _class: JClass,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        into_pointer(Expr::Literal(ScalarValue::Null))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_litBoolean(
+    env: JNIEnv,
+    _class: JClass,
+    value: jboolean,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        into_pointer(Expr::Literal(ScalarValue::Boolean(Some(value != 0u8))))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_litUtf8(
+    mut env: JNIEnv,
+    _class: JClass,
+    value: JString,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let value: String = env
+            .get_string(&value)
+            .expect("Couldn't get value as string!")
+            .into();
+        into_pointer(Expr::Literal(ScalarValue::Utf8(Some(value))))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_litBinary(
+    env: JNIEnv,
+    _class: JClass,
+    value: JByteArray,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let value = env
+            .convert_byte_array(&value)
+            .expect("Couldn't get value as byte array!");
+        into_pointer(Expr::Literal(ScalarValue::Binary(Some(value))))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_litDecimal128(
+    mut env: JNIEnv,
+    _class: JClass,
+    unscaled_value: JString,
+    precision: jint,
+    scale: jint,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let unscaled_value: String = env
+            .get_string(&unscaled_value)
+            .expect("Couldn't get unscaled_value as string!")
+            .into();
+        let result =
+            decimal_precision_and_scale(precision, scale).and_then(|(precision, scale)| {
+                let value = i128::from_str(&unscaled_value).map_err(|err| {
+                    DataFusionError::Plan(format!("Invalid decimal value: {err}"))
+                })?;
+                let literal = ScalarValue::Decimal128(Some(value), precision, scale);
+                Ok(into_pointer(Expr::Literal(literal)))
+            });
+        set_object_result(&mut env, object_result, result);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_litTimestampMicrosecond(
+    mut env: JNIEnv,
+    _class: JClass,
+    value: jlong,
+    timezone: JString,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let timezone: Option<String> = if timezone.is_null() {
+            None
+        } else {
+            Some(
+                env.get_string(&timezone)
+                    .expect("Couldn't get timezone as string!")
+                    .into(),
+            )
+        };
+        into_pointer(Expr::Literal(ScalarValue::TimestampMicrosecond(
+            Some(value),
+            timezone.map(Into::into),
+        )))
+    })
+}
+
+// Operators
+
+macro_rules! binary_operator {
+    ($name:ident, $operator:expr) => {
+        #[no_mangle]
+        pub extern "system" fn $name(
+            env: JNIEnv,
+            _class: JClass,
+            left: jlong,
+            right: jlong,
+        ) -> jlong {
+            catch_panic(env.get_raw(), || {
+                let left = expr_from_pointer(left);
+                let right = expr_from_pointer(right);
+                into_pointer(binary_expr(left, $operator, right))
+            })
+        }
+    };
+}
+
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_eq, Operator::Eq);
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_notEq, Operator::NotEq);
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_lt, Operator::Lt);
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_ltEq, Operator::LtEq);
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_gt, Operator::Gt);
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_gtEq, Operator::GtEq);
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_plus, Operator::Plus);
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_minus, Operator::Minus);
+binary_operator!(
+    Java_org_apache_arrow_datafusion_Expr_multiply,
+    Operator::Multiply
+);
+binary_operator!(
+    Java_org_apache_arrow_datafusion_Expr_divide,
+    Operator::Divide
+);
+binary_operator!(
+    Java_org_apache_arrow_datafusion_Expr_modulo,
+    Operator::Modulo
+);
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_and, Operator::And);
+binary_operator!(Java_org_apache_arrow_datafusion_Expr_or, Operator::Or);
+
+macro_rules! unary_operator {
+    ($name:ident, $function:expr) => {
+        #[no_mangle]
+        pub extern "system" fn $name(env: JNIEnv, _class: JClass, pointer: jlong) -> jlong {
+            catch_panic(env.get_raw(), || {
+                let expr = expr_from_pointer(pointer);
+                into_pointer($function(expr))
+            })
+        }
+    };
+}
+
+unary_operator!(Java_org_apache_arrow_datafusion_Expr_not, Expr::not);
+unary_operator!(Java_org_apache_arrow_datafusion_Expr_isNull, Expr::is_null);
+unary_operator!(
+    Java_org_apache_arrow_datafusion_Expr_isNotNull,
+    Expr::is_not_null
+);
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_like(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    pattern: jlong,
+    negated: jboolean,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let expr = expr_from_pointer(pointer);
+        let pattern = expr_from_pointer(pattern);
+        if negated == 1u8 {
+            into_pointer(expr.not_like(pattern))
+        } else {
+            into_pointer(expr.like(pattern))
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_inList(
+    mut env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    list: JLongArray,
+    negated: jboolean,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let expr = expr_from_pointer(pointer);
+        let list = exprs_from_pointers(&mut env, &list);
+        into_pointer(expr.in_list(list, negated == 1u8))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_cast(
+    mut env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    data_type_schema: jlong,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let result = import_data_type(data_type_schema).map(|data_type| {
+            let expr = expr_from_pointer(pointer);
+            into_pointer(cast(expr, data_type))
+        });
+        set_object_result(&mut env, object_result, result);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_caseWhen(
+    mut env: JNIEnv,
+    _class: JClass,
+    base: jlong,
+    when_exprs: JLongArray,
+    then_exprs: JLongArray,
+    else_expr: jlong,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let when_exprs = exprs_from_pointers(&mut env, &when_exprs);
+        let then_exprs = exprs_from_pointers(&mut env, &then_exprs);
+        let result = if when_exprs.len() != then_exprs.len() {
+            Err(DataFusionError::Plan(format!(
+                "CASE has {} WHEN expressions but {} THEN expressions",
+                when_exprs.len(),
+                then_exprs.len()
+            )))
+        } else {
+            let when_then_exprs = when_exprs
+                .into_iter()
+                .zip(then_exprs)
+                .map(|(when_expr, then_expr)| (Box::new(when_expr), Box::new(then_expr)))
+                .collect();
+            Ok(into_pointer(Expr::Case(Case::new(
+                optional_expr(base),
+                when_then_exprs,
+                optional_expr(else_expr),
+            ))))
+        };
+        set_object_result(&mut env, object_result, result);
+    })
+}
+
+// Scalar functions
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_Expr_scalarFunction(
+    mut env: JNIEnv,
+    _class: JClass,
+    name: JString,
+    args: JLongArray,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let name: String = env
+            .get_string(&name)
+            .expect("Couldn't get name as string!")
+            .into();
+        let args = exprs_from_pointers(&mut env, &args);
+        let result = BuiltinScalarFunction::from_str(&name).map(|fun| {
+            let expr = Expr::ScalarFunction(ScalarFunction::new(fun, args));
+            into_pointer(expr)
+        });
+        set_object_result(&mut env, object_result, result);
+    })
+}
+
+/// Check the precision and scale of a decimal literal are supported by Decimal128
+fn decimal_precision_and_scale(precision: jint, scale: jint) -> Result<(u8, i8)> {
+    if !(1..=DECIMAL128_MAX_PRECISION as jint).contains(&precision) {
+        return Err(DataFusionError::Plan(format!(
+            "Decimal precision must be between 1 and {DECIMAL128_MAX_PRECISION}: {precision}"
+        )));
+    }
+    if scale.abs() > precision {
+        return Err(DataFusionError::Plan(format!(
+            "Decimal scale {scale} is out of range for precision {precision}"
+        )));
+    }
+    Ok((precision as u8, scale as i8))
+}
@@ -0,0 +1,79 @@
+use arrow::array::{make_array, Array, ArrayRef};
+use arrow::error::ArrowError;
+use arrow::ffi::{ArrowArray, ArrowArrayRef, FFI_ArrowArray, FFI_ArrowSchema};
+use datafusion::error::{DataFusionError, Result};
+use jni::objects::JObject;
+use jni::sys::jlong;
+use jni::JNIEnv;
+
+use crate::util::jni_error;
+
+/// Arrays passed between Rust and Java using the Arrow C data interface
+pub struct FfiArrays {
+    arrays: Vec<FFI_ArrowArray>,
+    schemas: Vec<FFI_ArrowSchema>,
+}
+
+impl FfiArrays {
+    /// Export arrays so they can be imported by Java, which takes ownership of the array data
+    pub fn export(arrays: &[ArrayRef]) -> Result<Self> {
+        let schemas = arrays
+            .iter()
+            .map(|array| FFI_ArrowSchema::try_from(array.data_type()))
+            .collect::<std::result::Result<Vec<_>, ArrowError>>()?;
+        let arrays = arrays
+            .iter()
+            .map(|array| FFI_ArrowArray::new(&array.to_data()))
+            .collect();
+        Ok(Self { arrays, schemas })
+    }
+
+    /// Create empty arrays for Java to export data into
+    pub fn empty(count: usize) -> Self {
+        Self {
+            arrays: (0..count).map(|_| FFI_ArrowArray::empty()).collect(),
+            schemas: (0..count).map(|_| FFI_ArrowSchema::empty()).collect(),
+        }
+    }
+
+    /// Get the addresses of the array and schema at an index
+    pub fn address(&mut self, index: usize) -> (jlong, jlong) {
+        (
+            &mut self.arrays[index] as *mut FFI_ArrowArray as jlong,
+            &mut self.schemas[index] as *mut FFI_ArrowSchema as jlong,
+        )
+    }
+
+    /// Create Java long arrays holding the addresses of the arrays and of the schemas
+    pub fn java_addresses<'local>(
+        &mut self,
+        env: &mut JNIEnv<'local>,
+    ) -> Result<(JObject<'local>, JObject<'local>)> {
+        let (array_addresses, schema_addresses): (Vec<jlong>, Vec<jlong>) = (0..self.arrays.len())
+            .map(|index| self.address(index))
+            .unzip();
+        let java_arrays = env
+            .new_long_array(array_addresses.len() as i32)
+            .map_err(jni_error)?;
+        env.set_long_array_region(&java_arrays, 0, &array_addresses)
+            .map_err(jni_error)?;
+        let java_schemas = env
+            .new_long_array(schema_addresses.len() as i32)
+            .map_err(jni_error)?;
+        env.set_long_array_region(&java_schemas, 0, &schema_addresses)
+            .map_err(jni_error)?;
+        Ok((JObject::from(java_arrays), JObject::from(java_schemas)))
+    }
+
+    /// Import arrays that Java exported into these structs
+    pub fn import(self) -> Result<Vec<ArrayRef>> {
+        self.arrays
+            .into_iter()
+            .zip(self.schemas)
+            .map(|(array, schema)| {
+                let array_data = ArrowArray::new(array, schema).to_data()?;
+                Ok::<ArrayRef, DataFusionError>(make_array(array_data))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,17 @@
+use datafusion::datasource::file_format::file_type::FileCompressionType;
+use datafusion::error::{DataFusionError, Result};
+use jni::sys::jint;
+
+/// Convert the id of a Java FileCompressionType enum value into a FileCompressionType
+pub fn file_compression_type_from_id(id: jint) -> Result<FileCompressionType> {
+    match id {
+        0 => Ok(FileCompressionType::UNCOMPRESSED),
+        1 => Ok(FileCompressionType::GZIP),
+        2 => Ok(FileCompressionType::BZIP2),
+        3 => Ok(FileCompressionType::XZ),
+        4 => Ok(FileCompressionType::ZSTD),
+        _ => Err(DataFusionError::NotImplemented(format!(
+            "Unsupported file compression type id {id}"
+        ))),
+    }
+}
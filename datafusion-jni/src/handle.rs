@@ -0,0 +1,129 @@
+use std::any::{type_name, Any};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use datafusion::error::{DataFusionError, Result};
+use jni::sys::jlong;
+
+use crate::exception::throw_error;
+
+/// Native objects used from Java are stored in a table and referenced by handles
+/// rather than raw pointers. A handle combines the index of a slot in the table with the
+/// generation of the slot, which is incremented whenever an object is removed, so that
+/// using a closed handle, or a handle for an object of a different type, is reported
+/// as an error instead of accessing freed or wrongly typed memory.
+struct HandleTable {
+    slots: Vec<Slot>,
+    free_slots: Vec<usize>,
+}
+
+struct Slot {
+    generation: u32,
+    object: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+static HANDLES: Mutex<HandleTable> = Mutex::new(HandleTable {
+    slots: Vec::new(),
+    free_slots: Vec::new(),
+});
+
+/// Generations are limited to 31 bits so that handles are always positive,
+/// and zero is never a valid handle
+const MAX_GENERATION: u32 = 0x7fff_ffff;
+
+/// Store an object and get a new handle that references it
+pub fn insert<T: Any + Send + Sync>(object: T) -> jlong {
+    let object: Arc<dyn Any + Send + Sync> = Arc::new(object);
+    let mut handles = handles();
+    let index = match handles.free_slots.pop() {
+        Some(index) => {
+            handles.slots[index].object = Some(object);
+            index
+        }
+        None => {
+            handles.slots.push(Slot {
+                generation: 1,
+                object: Some(object),
+            });
+            handles.slots.len() - 1
+        }
+    };
+    encode(index, handles.slots[index].generation)
+}
+
+/// Get the object referenced by a handle, or an error if the handle has been closed
+/// or does not reference an object of the expected type
+pub fn try_get<T: Any + Send + Sync>(handle: jlong) -> Result<Arc<T>> {
+    let (index, generation) = decode(handle);
+    handles()
+        .slots
+        .get(index)
+        .filter(|slot| slot.generation == generation)
+        .and_then(|slot| slot.object.clone())
+        .and_then(|object| object.downcast::<T>().ok())
+        .ok_or_else(|| invalid_handle::<T>(handle))
+}
+
+/// Get the object referenced by a handle, throwing a Java exception from the current
+/// JNI call if the handle is invalid
+pub fn get<T: Any + Send + Sync>(handle: jlong) -> Arc<T> {
+    try_get(handle).unwrap_or_else(|err| throw_error(err))
+}
+
+/// Remove the object referenced by a handle so that the handle can no longer be used,
+/// or get an error if the handle has already been closed or is for a different type.
+/// The object is dropped once any calls that are still using it complete.
+pub fn try_remove<T: Any + Send + Sync>(handle: jlong) -> Result<Arc<T>> {
+    let (index, generation) = decode(handle);
+    let mut handles = handles();
+    let object = handles
+        .slots
+        .get_mut(index)
+        .filter(|slot| slot.generation == generation)
+        .filter(|slot| slot.object.as_ref().is_some_and(|object| object.is::<T>()))
+        .and_then(|slot| {
+            slot.generation = if slot.generation == MAX_GENERATION {
+                1
+            } else {
+                slot.generation + 1
+            };
+            slot.object.take()
+        });
+    match object.and_then(|object| object.downcast::<T>().ok()) {
+        Some(object) => {
+            handles.free_slots.push(index);
+            Ok(object)
+        }
+        None => Err(invalid_handle::<T>(handle)),
+    }
+}
+
+/// Remove the object referenced by a handle, throwing a Java exception from the current
+/// JNI call if the handle is invalid
+pub fn remove<T: Any + Send + Sync>(handle: jlong) -> Arc<T> {
+    try_remove(handle).unwrap_or_else(|err| throw_error(err))
+}
+
+/// Lock a mutex that guards a mutable object referenced by a handle.
+/// A panic while the lock was held does not prevent further use of the object.
+pub fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn handles() -> MutexGuard<'static, HandleTable> {
+    lock(&HANDLES)
+}
+
+fn encode(index: usize, generation: u32) -> jlong {
+    ((generation as jlong) << 32) | index as jlong
+}
+
+fn decode(handle: jlong) -> (usize, u32) {
+    ((handle & 0xffff_ffff) as usize, (handle >> 32) as u32)
+}
+
+fn invalid_handle<T>(handle: jlong) -> DataFusionError {
+    DataFusionError::Execution(format!(
+        "Closed or invalid handle {handle:#x} for {}",
+        type_name::<T>()
+    ))
+}
@@ -0,0 +1,96 @@
+use std::ffi::c_void;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::OnceLock;
+
+use datafusion::error::Result;
+use jni::objects::{GlobalRef, JObject};
+use jni::sys::{jint, JNI_ERR, JNI_VERSION_1_8};
+use jni::{AttachGuard, JNIEnv, JavaVM};
+
+use crate::exception::{cache_exception_class, panic_error};
+use crate::util::{java_call_error, jni_error};
+
+/// The Java VM that loaded this library, used to attach native threads
+static JAVA_VM: OnceLock<JavaVM> = OnceLock::new();
+
+/// Maximum number of local references created when calling a Java callback from a runtime thread
+const CALLBACK_LOCAL_FRAME_CAPACITY: i32 = 16;
+
+#[no_mangle]
+pub extern "system" fn JNI_OnLoad(vm: *mut jni::sys::JavaVM, _reserved: *mut c_void) -> jint {
+    let vm = match unsafe { JavaVM::from_raw(vm) } {
+        Ok(vm) => vm,
+        Err(_) => return JNI_ERR,
+    };
+    // Classes must be looked up here rather than from native threads
+    if let Ok(mut env) = vm.get_env() {
+        if cache_exception_class(&mut env).is_err() {
+            return JNI_ERR;
+        }
+    }
+    let _ = JAVA_VM.set(vm);
+    JNI_VERSION_1_8
+}
+
+/// Get the Java VM that loaded this library
+pub fn java_vm(env: &JNIEnv) -> Result<&'static JavaVM> {
+    match JAVA_VM.get() {
+        Some(vm) => Ok(vm),
+        None => {
+            let vm = env.get_java_vm().map_err(jni_error)?;
+            Ok(JAVA_VM.get_or_init(|| vm))
+        }
+    }
+}
+
+/// A global reference to a Java object whose methods are called from native threads
+pub struct JavaCallbacks {
+    jvm: &'static JavaVM,
+    object: GlobalRef,
+}
+
+impl JavaCallbacks {
+    pub fn new(env: &mut JNIEnv, object: JObject) -> Result<Self> {
+        let jvm = java_vm(env)?;
+        let object = env.new_global_ref(object).map_err(jni_error)?;
+        Ok(Self { jvm, object })
+    }
+
+    /// Attach the current thread to the JVM, if it is not already attached
+    pub fn attach(&self) -> Result<AttachGuard<'_>> {
+        self.jvm.attach_current_thread().map_err(jni_error)
+    }
+
+    /// Call the Java object from the current thread within a new local reference frame,
+    /// so that local references created by the call are freed when it returns, even if
+    /// the thread stays attached to the JVM
+    pub fn call<T>(&self, call: impl FnOnce(&mut JNIEnv, &JObject) -> Result<T>) -> Result<T> {
+        let mut env = self.attach()?;
+        env.with_local_frame(CALLBACK_LOCAL_FRAME_CAPACITY, |env| {
+            Ok::<_, jni::errors::Error>(call(env, self.object.as_obj()))
+        })
+        .map_err(jni_error)?
+    }
+
+    /// Call the Java object from a Tokio runtime thread.
+    /// The thread stays attached to the JVM as a daemon thread so later callbacks are cheap,
+    /// and local references are freed when the call returns. A panic in the call, or an
+    /// exception thrown by Java, is cleared and returned as an error.
+    pub fn call_from_runtime(&self, call: impl FnOnce(&mut JNIEnv, &JObject)) -> Result<()> {
+        let mut env = self
+            .jvm
+            .attach_current_thread_as_daemon()
+            .map_err(jni_error)?;
+        env.with_local_frame(CALLBACK_LOCAL_FRAME_CAPACITY, |env| {
+            let result = catch_unwind(AssertUnwindSafe(|| call(env, self.object.as_obj())))
+                .map_err(panic_error);
+            // Clear any pending exception so that the thread can make further JNI calls
+            let exception = match env.exception_check() {
+                Ok(true) => Err(java_call_error(env, jni::errors::Error::JavaException)),
+                _ => Ok(()),
+            };
+            Ok::<_, jni::errors::Error>(result.and(exception))
+        })
+        .map_err(jni_error)?
+    }
+}
@@ -0,0 +1,20 @@
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::JoinType;
+use jni::sys::jint;
+
+/// Convert the id of a Java JoinType enum value into a JoinType
+pub fn join_type_from_id(id: jint) -> Result<JoinType> {
+    match id {
+        0 => Ok(JoinType::Inner),
+        1 => Ok(JoinType::Left),
+        2 => Ok(JoinType::Right),
+        3 => Ok(JoinType::Full),
+        4 => Ok(JoinType::LeftSemi),
+        5 => Ok(JoinType::RightSemi),
+        6 => Ok(JoinType::LeftAnti),
+        7 => Ok(JoinType::RightAnti),
+        _ => Err(DataFusionError::NotImplemented(format!(
+            "Unsupported join type id {id}"
+        ))),
+    }
+}
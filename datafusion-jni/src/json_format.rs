@@ -0,0 +1,33 @@
+use datafusion::datasource::file_format::json::JsonFormat;
+use datafusion::datasource::file_format::FileFormat;
+use jni::objects::JClass;
+use jni::sys::jlong;
+use jni::JNIEnv;
+use std::sync::Arc;
+
+use crate::exception::catch_panic;
+use crate::handle;
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_JsonFormat_create(
+    env: JNIEnv,
+    _class: JClass,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        // Return as an Arc<dyn FileFormat> rather than JsonFormat so this
+        // can be passed into ListingOptions.create
+        let format: Arc<dyn FileFormat> = Arc::new(JsonFormat::default());
+        handle::insert(format)
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_JsonFormat_destroy(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Arc<dyn FileFormat>>(pointer);
+    })
+}
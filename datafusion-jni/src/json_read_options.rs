@@ -0,0 +1,114 @@
+use datafusion::datasource::file_format::file_type::FileCompressionType;
+use datafusion::prelude::NdJsonReadOptions;
+use jni::objects::{JClass, JObject, JString};
+use jni::sys::{jint, jlong};
+use jni::JNIEnv;
+use std::sync::Mutex;
+
+use crate::exception::catch_panic;
+use crate::file_compression_type::file_compression_type_from_id;
+use crate::handle;
+use crate::util::result_exception;
+
+/// Owned version of NdJsonReadOptions, which borrows its file extension
+/// so cannot be stored behind a handle held by Java
+#[derive(Clone)]
+pub struct OwnedNdJsonReadOptions {
+    schema_infer_max_records: usize,
+    file_extension: String,
+    file_compression_type: FileCompressionType,
+}
+
+impl OwnedNdJsonReadOptions {
+    /// Get NdJsonReadOptions that borrow from these options
+    pub fn to_read_options(&self) -> NdJsonReadOptions<'_> {
+        NdJsonReadOptions {
+            schema_infer_max_records: self.schema_infer_max_records,
+            file_extension: &self.file_extension,
+            file_compression_type: self.file_compression_type.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for OwnedNdJsonReadOptions {
+    fn default() -> Self {
+        let defaults = NdJsonReadOptions::default();
+        Self {
+            schema_infer_max_records: defaults.schema_infer_max_records,
+            file_extension: defaults.file_extension.to_string(),
+            file_compression_type: defaults.file_compression_type,
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_NdJsonReadOptions_create(
+    env: JNIEnv,
+    _class: JClass,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        handle::insert(Mutex::new(OwnedNdJsonReadOptions::default()))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_NdJsonReadOptions_destroy(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Mutex<OwnedNdJsonReadOptions>>(pointer);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_NdJsonReadOptions_setSchemaInferMaxRecords(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    max_records: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedNdJsonReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        options.schema_infer_max_records = max_records as usize;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_NdJsonReadOptions_setFileExtension(
+    mut env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    file_extension: JString,
+) {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedNdJsonReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        let file_extension: String = env
+            .get_string(&file_extension)
+            .expect("Couldn't get file_extension as string!")
+            .into();
+        options.file_extension = file_extension;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_NdJsonReadOptions_setFileCompressionType<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pointer: jlong,
+    compression_type_id: jint,
+) -> JObject<'local> {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedNdJsonReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        let result = file_compression_type_from_id(compression_type_id)
+            .map(|compression_type| options.file_compression_type = compression_type);
+        result_exception(&mut env, result)
+    })
+}
@@ -1,12 +1,33 @@
 mod arrow_format;
+mod async_callback;
+mod avro_format;
 mod context;
 mod csv_format;
+mod csv_read_options;
+mod custom_table;
 mod dataframe;
+mod exception;
+mod expr;
+mod ffi_arrays;
+mod file_compression_type;
+mod handle;
+mod java_callbacks;
+mod join_type;
+mod json_format;
+mod json_read_options;
 mod listing_options;
 mod listing_table;
 mod listing_table_config;
+mod mem_table;
 mod parquet_format;
+mod parquet_read_options;
 mod runtime;
+mod runtime_config;
 mod session_config;
+mod spill_metrics;
 mod stream;
+mod stream_table;
+mod udaf;
+mod udf;
+mod udf_library;
 mod util;
@@ -5,6 +5,9 @@ use jni::sys::{jboolean, jlong};
 use jni::JNIEnv;
 use std::sync::Arc;
 
+use crate::exception::catch_panic;
+use crate::handle;
+
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_ListingOptions_create(
     mut env: JNIEnv,
@@ -13,24 +16,28 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_ListingOptions_create(
     file_extension: JString,
     collect_stat: jboolean,
 ) -> jlong {
-    let format = unsafe { &*(format as *const Arc<dyn FileFormat>) };
+    catch_panic(env.get_raw(), || {
+        let format = handle::get::<Arc<dyn FileFormat>>(format);
 
-    let file_extension: String = env
-        .get_string(&file_extension)
-        .expect("Couldn't get Java file_extension string")
-        .into();
+        let file_extension: String = env
+            .get_string(&file_extension)
+            .expect("Couldn't get Java file_extension string")
+            .into();
 
-    let listing_options = ListingOptions::new(format.clone())
-        .with_file_extension(file_extension)
-        .with_collect_stat(collect_stat == 1u8);
-    Box::into_raw(Box::new(listing_options)) as jlong
+        let listing_options = ListingOptions::new(format.as_ref().clone())
+            .with_file_extension(file_extension)
+            .with_collect_stat(collect_stat == 1u8);
+        handle::insert(listing_options)
+    })
 }
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_ListingOptions_destroy(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     pointer: jlong,
 ) {
-    let _ = unsafe { Box::from_raw(pointer as *mut ListingOptions) };
+    catch_panic(env.get_raw(), || {
+        handle::remove::<ListingOptions>(pointer);
+    })
 }
@@ -0,0 +1,76 @@
+use datafusion::datasource::{MemTable, TableProvider};
+use datafusion::error::{DataFusionError, Result};
+use jni::objects::{JClass, JLongArray, JObject};
+use jni::sys::jlong;
+use jni::JNIEnv;
+use std::sync::Arc;
+
+use crate::exception::catch_panic;
+use crate::handle;
+use crate::util::{import_record_batch, import_schema, set_object_result};
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_MemTable_create(
+    mut env: JNIEnv,
+    _class: JClass,
+    schema: jlong,
+    arrays: JLongArray,
+    array_schemas: JLongArray,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let arrays = long_array_values(&mut env, &arrays);
+        let array_schemas = long_array_values(&mut env, &array_schemas);
+        let table_provider_result =
+            create_mem_table(schema, arrays, array_schemas).map(|mem_table| {
+                // Return as an Arc<dyn TableProvider> so this can be passed into SessionContext.registerTable
+                let table_provider: Arc<dyn TableProvider> = Arc::new(mem_table);
+                handle::insert(table_provider)
+            });
+        set_object_result(&mut env, object_result, table_provider_result);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_MemTable_destroy(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Arc<dyn TableProvider>>(pointer);
+    })
+}
+
+fn create_mem_table(
+    schema: jlong,
+    arrays: Vec<jlong>,
+    array_schemas: Vec<jlong>,
+) -> Result<MemTable> {
+    if arrays.len() != array_schemas.len() {
+        return Err(DataFusionError::Plan(format!(
+            "Got {} arrays but {} array schemas",
+            arrays.len(),
+            array_schemas.len()
+        )));
+    }
+    let schema = Arc::new(import_schema(schema)?);
+    let batches = arrays
+        .into_iter()
+        .zip(array_schemas)
+        .map(|(array, array_schema)| {
+            import_record_batch(array, array_schema).map_err(DataFusionError::from)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    MemTable::try_new(schema, vec![batches])
+}
+
+fn long_array_values(env: &mut JNIEnv, array: &JLongArray) -> Vec<jlong> {
+    let length = env
+        .get_array_length(array)
+        .expect("Couldn't get array length");
+    let mut values = vec![0; length as usize];
+    env.get_long_array_region(array, 0, &mut values)
+        .expect("Couldn't get array values");
+    values
+}
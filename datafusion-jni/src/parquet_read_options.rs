@@ -0,0 +1,134 @@
+use arrow::datatypes::DataType;
+use datafusion::prelude::ParquetReadOptions;
+use jni::objects::{JClass, JObject, JString};
+use jni::sys::{jboolean, jlong};
+use jni::JNIEnv;
+use std::sync::Mutex;
+
+use crate::exception::catch_panic;
+use crate::handle;
+use crate::util::{import_schema, result_exception};
+
+/// Owned version of ParquetReadOptions, which borrows its file extension
+/// so cannot be stored behind a handle held by Java
+#[derive(Clone)]
+pub struct OwnedParquetReadOptions {
+    file_extension: String,
+    table_partition_cols: Vec<(String, DataType)>,
+    parquet_pruning: Option<bool>,
+    skip_metadata: Option<bool>,
+}
+
+impl OwnedParquetReadOptions {
+    /// Get ParquetReadOptions that borrow from these options
+    pub fn to_read_options(&self) -> ParquetReadOptions<'_> {
+        let mut options =
+            ParquetReadOptions::default().table_partition_cols(self.table_partition_cols.clone());
+        options.file_extension = &self.file_extension;
+        options.parquet_pruning = self.parquet_pruning;
+        options.skip_metadata = self.skip_metadata;
+        options
+    }
+}
+
+impl Default for OwnedParquetReadOptions {
+    fn default() -> Self {
+        let defaults = ParquetReadOptions::default();
+        Self {
+            file_extension: defaults.file_extension.to_string(),
+            table_partition_cols: defaults.table_partition_cols,
+            parquet_pruning: defaults.parquet_pruning,
+            skip_metadata: defaults.skip_metadata,
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_ParquetReadOptions_create(
+    env: JNIEnv,
+    _class: JClass,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        handle::insert(Mutex::new(OwnedParquetReadOptions::default()))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_ParquetReadOptions_destroy(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Mutex<OwnedParquetReadOptions>>(pointer);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_ParquetReadOptions_setFileExtension(
+    mut env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    file_extension: JString,
+) {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedParquetReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        let file_extension: String = env
+            .get_string(&file_extension)
+            .expect("Couldn't get file_extension as string!")
+            .into();
+        options.file_extension = file_extension;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_ParquetReadOptions_setTablePartitionCols<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pointer: jlong,
+    partition_schema: jlong,
+) -> JObject<'local> {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedParquetReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        let result = import_schema(partition_schema).map(|partition_schema| {
+            options.table_partition_cols = partition_schema
+                .fields()
+                .iter()
+                .map(|field| (field.name().clone(), field.data_type().clone()))
+                .collect();
+        });
+        result_exception(&mut env, result)
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_ParquetReadOptions_setParquetPruning(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    enabled: jboolean,
+) {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedParquetReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        options.parquet_pruning = Some(enabled != 0u8);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_ParquetReadOptions_setSkipMetadata(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    enabled: jboolean,
+) {
+    catch_panic(env.get_raw(), || {
+        let options = handle::get::<Mutex<OwnedParquetReadOptions>>(pointer);
+        let mut options = handle::lock(&options);
+        options.skip_metadata = Some(enabled != 0u8);
+    })
+}
@@ -1,29 +1,195 @@
-use jni::objects::JClass;
-use jni::sys::jlong;
+use datafusion::error::{DataFusionError, Result};
+use jni::objects::{JClass, JString};
+use jni::sys::{jboolean, jint, jlong, JNI_TRUE};
 use jni::JNIEnv;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::runtime::Runtime;
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio::task::JoinHandle;
+use tokio_util::sync::{CancellationToken, DropGuard};
+
+use crate::exception::{catch_panic, throw_error};
+use crate::handle;
+use crate::util::jni_error;
+
+/// How long to wait for spawned tasks to stop when a runtime is destroyed
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// A Tokio runtime that runs native tasks for a Java SessionContext
+pub enum TokioRuntime {
+    /// Tasks are run by the worker threads owned by the runtime
+    MultiThread(Runtime),
+    /// Tasks are run by a single thread, which drives the runtime until the shutdown
+    /// guard is dropped
+    CurrentThread { handle: Handle, shutdown: DropGuard },
+}
+
+impl TokioRuntime {
+    /// Spawn a task onto the runtime
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        match self {
+            TokioRuntime::MultiThread(runtime) => runtime.spawn(future),
+            TokioRuntime::CurrentThread { handle, .. } => handle.spawn(future),
+        }
+    }
+
+    /// Shut down the runtime. Tasks that have not completed are dropped, which completes
+    /// their Java callbacks with a cancellation error.
+    fn shutdown(self) {
+        match self {
+            // Destroying the runtime from one of its own threads, for example in a callback,
+            // must not block waiting for that thread to stop
+            TokioRuntime::MultiThread(runtime) if Handle::try_current().is_ok() => {
+                runtime.shutdown_background()
+            }
+            TokioRuntime::MultiThread(runtime) => runtime.shutdown_timeout(SHUTDOWN_TIMEOUT),
+            TokioRuntime::CurrentThread { shutdown, .. } => drop(shutdown),
+        }
+    }
+}
+
+/// Options for building a runtime. Unset options use the Tokio defaults.
+struct RuntimeOptions {
+    current_thread: bool,
+    worker_threads: Option<usize>,
+    max_blocking_threads: Option<usize>,
+    thread_name_prefix: Option<String>,
+    thread_stack_size: Option<usize>,
+    thread_keep_alive: Option<Duration>,
+}
+
+impl RuntimeOptions {
+    fn build(self) -> Result<TokioRuntime> {
+        let mut builder = if self.current_thread {
+            Builder::new_current_thread()
+        } else {
+            Builder::new_multi_thread()
+        };
+        builder.enable_all();
+        if let Some(worker_threads) = self.worker_threads {
+            builder.worker_threads(worker_threads);
+        }
+        if let Some(max_blocking_threads) = self.max_blocking_threads {
+            builder.max_blocking_threads(max_blocking_threads);
+        }
+        if let Some(prefix) = self.thread_name_prefix.clone() {
+            let thread_id = AtomicUsize::new(0);
+            builder.thread_name_fn(move || {
+                let id = thread_id.fetch_add(1, Ordering::Relaxed);
+                format!("{prefix}-{id}")
+            });
+        }
+        if let Some(thread_stack_size) = self.thread_stack_size {
+            builder.thread_stack_size(thread_stack_size);
+        }
+        if let Some(thread_keep_alive) = self.thread_keep_alive {
+            builder.thread_keep_alive(thread_keep_alive);
+        }
+        let runtime = builder.build().map_err(|err| runtime_error(err.into()))?;
+        if self.current_thread {
+            self.drive(runtime)
+        } else {
+            Ok(TokioRuntime::MultiThread(runtime))
+        }
+    }
+
+    /// Start a thread to drive a current thread runtime, as tasks are only run
+    /// while a thread is blocked on the runtime
+    fn drive(self, runtime: Runtime) -> Result<TokioRuntime> {
+        let handle = runtime.handle().clone();
+        let shutdown = CancellationToken::new();
+        let mut thread = std::thread::Builder::new().name(match self.thread_name_prefix {
+            Some(prefix) => format!("{prefix}-driver"),
+            None => "tokio-runtime-driver".to_string(),
+        });
+        if let Some(thread_stack_size) = self.thread_stack_size {
+            thread = thread.stack_size(thread_stack_size);
+        }
+        let stopped = shutdown.clone();
+        thread
+            .spawn(move || {
+                runtime.block_on(stopped.cancelled());
+                runtime.shutdown_timeout(SHUTDOWN_TIMEOUT);
+            })
+            .map_err(|err| runtime_error(err.into()))?;
+        Ok(TokioRuntime::CurrentThread {
+            handle,
+            shutdown: shutdown.drop_guard(),
+        })
+    }
+}
+
+fn runtime_error(error: DataFusionError) -> DataFusionError {
+    DataFusionError::Context(
+        "Failed to create Tokio runtime".to_string(),
+        Box::new(error),
+    )
+}
+
+/// Convert an optional Java option value, where zero or a negative value means it is unset
+fn optional<T: TryFrom<i64>>(value: i64) -> Option<T> {
+    if value > 0 {
+        T::try_from(value).ok()
+    } else {
+        None
+    }
+}
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_TokioRuntime_createTokioRuntime(
-    _env: JNIEnv,
+    mut env: JNIEnv,
     _class: JClass,
+    current_thread: jboolean,
+    worker_threads: jint,
+    max_blocking_threads: jint,
+    thread_name_prefix: JString,
+    thread_stack_size: jlong,
+    thread_keep_alive_nanos: jlong,
 ) -> jlong {
-    if let Ok(runtime) = Runtime::new() {
-        // println!("successfully created tokio runtime");
-        Box::into_raw(Box::new(runtime)) as jlong
-    } else {
-        // TODO error handling
-        -1
-    }
+    catch_panic(env.get_raw(), || {
+        let thread_name_prefix = if thread_name_prefix.is_null() {
+            None
+        } else {
+            let prefix = env
+                .get_string(&thread_name_prefix)
+                .map_err(jni_error)
+                .unwrap_or_else(|err| throw_error(err));
+            Some(prefix.into())
+        };
+        let options = RuntimeOptions {
+            current_thread: current_thread == JNI_TRUE,
+            worker_threads: optional(worker_threads.into()),
+            max_blocking_threads: optional(max_blocking_threads.into()),
+            thread_name_prefix,
+            thread_stack_size: optional(thread_stack_size),
+            thread_keep_alive: if thread_keep_alive_nanos >= 0 {
+                Some(Duration::from_nanos(thread_keep_alive_nanos as u64))
+            } else {
+                None
+            },
+        };
+        let runtime = options.build().unwrap_or_else(|err| throw_error(err));
+        handle::insert(runtime)
+    })
 }
+
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_TokioRuntime_destroyTokioRuntime(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     pointer: jlong,
 ) {
-    let runtime = unsafe { Box::from_raw(pointer as *mut Runtime) };
-    runtime.shutdown_timeout(Duration::from_millis(100));
-    // println!("successfully shutdown tokio runtime");
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::remove::<TokioRuntime>(pointer);
+        // Calls still running on other threads keep the runtime alive until they complete
+        if let Ok(runtime) = Arc::try_unwrap(runtime) {
+            runtime.shutdown();
+        }
+    })
 }
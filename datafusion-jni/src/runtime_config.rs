@@ -0,0 +1,146 @@
+use datafusion::error::Result;
+use datafusion::execution::disk_manager::DiskManagerConfig;
+use datafusion::execution::memory_pool::{FairSpillPool, GreedyMemoryPool, MemoryPool};
+use datafusion::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
+use jni::objects::{JClass, JObjectArray};
+use jni::sys::jlong;
+use jni::JNIEnv;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::exception::catch_panic;
+use crate::handle;
+use crate::util::string_array;
+
+/// The memory pool used to limit the memory used by queries
+#[derive(Clone, Copy)]
+enum MemoryPoolConfig {
+    /// Allocate memory on a first come first served basis, up to a limit
+    Greedy(usize),
+    /// Share memory fairly between operators that can spill to disk, up to a limit
+    FairSpill(usize),
+}
+
+/// Configuration used to create a RuntimeEnv for each SessionContext.
+/// A RuntimeConfig is not used directly, as its memory pool would be shared
+/// between all contexts created from the same configuration.
+#[derive(Clone, Default)]
+pub struct OwnedRuntimeConfig {
+    memory_pool: Option<MemoryPoolConfig>,
+    disk_manager: DiskManagerConfig,
+}
+
+impl OwnedRuntimeConfig {
+    /// Create a new RuntimeEnv from this configuration
+    pub fn runtime_env(&self) -> Result<Arc<RuntimeEnv>> {
+        let mut config = RuntimeConfig::new().with_disk_manager(self.disk_manager.clone());
+        if let Some(memory_pool) = self.memory_pool {
+            let memory_pool: Arc<dyn MemoryPool> = match memory_pool {
+                MemoryPoolConfig::Greedy(size) => Arc::new(GreedyMemoryPool::new(size)),
+                MemoryPoolConfig::FairSpill(size) => Arc::new(FairSpillPool::new(size)),
+            };
+            config = config.with_memory_pool(memory_pool);
+        }
+        Ok(Arc::new(RuntimeEnv::new(config)?))
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_RuntimeConfig_create(
+    env: JNIEnv,
+    _class: JClass,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        handle::insert(Mutex::new(OwnedRuntimeConfig::default()))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_RuntimeConfig_destroy(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Mutex<OwnedRuntimeConfig>>(pointer);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_RuntimeConfig_setGreedyMemoryPool(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    pool_size: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<OwnedRuntimeConfig>>(pointer);
+        handle::lock(&config).memory_pool = Some(MemoryPoolConfig::Greedy(pool_size as usize));
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_RuntimeConfig_setFairSpillPool(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    pool_size: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<OwnedRuntimeConfig>>(pointer);
+        handle::lock(&config).memory_pool = Some(MemoryPoolConfig::FairSpill(pool_size as usize));
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_RuntimeConfig_setUnboundedMemoryPool(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<OwnedRuntimeConfig>>(pointer);
+        handle::lock(&config).memory_pool = None;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_RuntimeConfig_setDiskManagerDisabled(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<OwnedRuntimeConfig>>(pointer);
+        handle::lock(&config).disk_manager = DiskManagerConfig::Disabled;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_RuntimeConfig_setDiskManagerOsTempDir(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<OwnedRuntimeConfig>>(pointer);
+        handle::lock(&config).disk_manager = DiskManagerConfig::NewOs;
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_RuntimeConfig_setDiskManagerSpillDirectories(
+    mut env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+    directories: JObjectArray,
+) {
+    catch_panic(env.get_raw(), || {
+        let directories = string_array(&mut env, &directories)
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+        let config = handle::get::<Mutex<OwnedRuntimeConfig>>(pointer);
+        handle::lock(&config).disk_manager = DiskManagerConfig::NewSpecified(directories);
+    })
+}
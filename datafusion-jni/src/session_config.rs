@@ -2,23 +2,30 @@ use datafusion::execution::context::SessionConfig;
 use jni::objects::{JClass, JObject, JString};
 use jni::sys::{jboolean, jlong};
 use jni::JNIEnv;
+use std::sync::Mutex;
+
+use crate::exception::catch_panic;
+use crate::handle;
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_SessionConfig_create(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
 ) -> jlong {
-    let session_config = Box::new(SessionConfig::new());
-    Box::into_raw(session_config) as jlong
+    catch_panic(env.get_raw(), || {
+        handle::insert(Mutex::new(SessionConfig::new()))
+    })
 }
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_SessionConfig_destroy(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     pointer: jlong,
 ) {
-    let _ = unsafe { Box::from_raw(pointer as *mut SessionConfig) };
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Mutex<SessionConfig>>(pointer);
+    })
 }
 
 // Helper macros to implement boolean options
@@ -27,17 +34,20 @@ macro_rules! bool_getter {
     ($name:ident, $($property_path:ident).+) => {
         #[no_mangle]
         pub extern "system" fn $name(
-            _env: JNIEnv,
+            env: JNIEnv,
             _class: JClass,
             pointer: jlong,
         ) -> jboolean {
-            let config = unsafe { &*(pointer as *const SessionConfig) };
-            let property_value = config.options().$($property_path).+;
-            if property_value {
-                1u8
-            } else {
-                0u8
-            }
+            catch_panic(env.get_raw(), || {
+                let config = handle::get::<Mutex<SessionConfig>>(pointer);
+                let config = handle::lock(&config);
+                let property_value = config.options().$($property_path).+;
+                if property_value {
+                    1u8
+                } else {
+                    0u8
+                }
+            })
         }
     }
 }
@@ -46,13 +56,16 @@ macro_rules! bool_setter {
     ($name:ident, $($property_path:ident).+) => {
         #[no_mangle]
         pub extern "system" fn $name(
-            _env: JNIEnv,
+            env: JNIEnv,
             _class: JClass,
             pointer: jlong,
             enabled: jboolean,
         ) {
-            let config = unsafe { &mut *(pointer as *mut SessionConfig) };
-            config.options_mut().$($property_path).+ = enabled != 0u8;
+            catch_panic(env.get_raw(), || {
+                let config = handle::get::<Mutex<SessionConfig>>(pointer);
+                let mut config = handle::lock(&config);
+                config.options_mut().$($property_path).+ = enabled != 0u8;
+            })
         }
     }
 }
@@ -61,13 +74,16 @@ macro_rules! usize_getter {
     ($name:ident, $($property_path:ident).+) => {
         #[no_mangle]
         pub extern "system" fn $name(
-            _env: JNIEnv,
+            env: JNIEnv,
             _class: JClass,
             pointer: jlong,
         ) -> jlong {
-            let config = unsafe { &*(pointer as *const SessionConfig) };
-            let property_value = config.options().$($property_path).+;
-            property_value as jlong
+            catch_panic(env.get_raw(), || {
+                let config = handle::get::<Mutex<SessionConfig>>(pointer);
+                let config = handle::lock(&config);
+                let property_value = config.options().$($property_path).+;
+                property_value as jlong
+            })
         }
     }
 }
@@ -76,13 +92,16 @@ macro_rules! usize_setter {
     ($name:ident, $($property_path:ident).+) => {
         #[no_mangle]
         pub extern "system" fn $name(
-            _env: JNIEnv,
+            env: JNIEnv,
             _class: JClass,
             pointer: jlong,
             value: jlong,
         ) {
-            let config = unsafe { &mut *(pointer as *mut SessionConfig) };
-            config.options_mut().$($property_path).+ = value as usize;
+            catch_panic(env.get_raw(), || {
+                let config = handle::get::<Mutex<SessionConfig>>(pointer);
+                let mut config = handle::lock(&config);
+                config.options_mut().$($property_path).+ = value as usize;
+            })
         }
     }
 }
@@ -179,31 +198,34 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_SessionConfig_getParquet
     pointer: jlong,
     on_value: JObject,
 ) {
-    let config = unsafe { &*(pointer as *const SessionConfig) };
-    let size_hint = config.options().execution.parquet.metadata_size_hint;
-    match size_hint {
-        Some(size_hint) => {
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<SessionConfig>>(pointer);
+        let config = handle::lock(&config);
+        let size_hint = config.options().execution.parquet.metadata_size_hint;
+        if let Some(size_hint) = size_hint {
             env.call_method(on_value, "accept", "(J)V", &[(size_hint as jlong).into()])
                 .expect("failed to call method");
         }
-        None => {}
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_SessionConfig_setParquetOptionsMetadataSizeHint(
-    _env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     pointer: jlong,
     has_value: jboolean,
     value: jlong,
 ) {
-    let config = unsafe { &mut *(pointer as *mut SessionConfig) };
-    if has_value == 1u8 {
-        config.options_mut().execution.parquet.metadata_size_hint = Some(value as usize);
-    } else {
-        config.options_mut().execution.parquet.metadata_size_hint = None;
-    }
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<SessionConfig>>(pointer);
+        let mut config = handle::lock(&config);
+        if has_value == 1u8 {
+            config.options_mut().execution.parquet.metadata_size_hint = Some(value as usize);
+        } else {
+            config.options_mut().execution.parquet.metadata_size_hint = None;
+        }
+    })
 }
 
 // SqlParserOptions
@@ -234,10 +256,13 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_SessionConfig_getSqlPars
     _class: JClass<'local>,
     pointer: jlong,
 ) -> JString<'local> {
-    let config = unsafe { &*(pointer as *const SessionConfig) };
-    let dialect = &config.options().sql_parser.dialect;
-    env.new_string(dialect)
-        .expect("Couldn't create Java string")
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<SessionConfig>>(pointer);
+        let config = handle::lock(&config);
+        let dialect = &config.options().sql_parser.dialect;
+        env.new_string(dialect)
+            .expect("Couldn't create Java string")
+    })
 }
 
 #[no_mangle]
@@ -247,10 +272,13 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_SessionConfig_setSqlPars
     pointer: jlong,
     dialect: JString,
 ) {
-    let config = unsafe { &mut *(pointer as *mut SessionConfig) };
-    let dialect: String = env
-        .get_string(&dialect)
-        .expect("Couldn't get dialect string")
-        .into();
-    config.options_mut().sql_parser.dialect = dialect;
+    catch_panic(env.get_raw(), || {
+        let config = handle::get::<Mutex<SessionConfig>>(pointer);
+        let mut config = handle::lock(&config);
+        let dialect: String = env
+            .get_string(&dialect)
+            .expect("Couldn't get dialect string")
+            .into();
+        config.options_mut().sql_parser.dialect = dialect;
+    })
 }
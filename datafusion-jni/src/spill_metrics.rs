@@ -0,0 +1,123 @@
+use datafusion::dataframe::DataFrame;
+use datafusion::error::Result;
+use datafusion::execution::context::{SessionState, TaskContext};
+use datafusion::physical_plan::{execute_stream, ExecutionPlan, SendableRecordBatchStream};
+use datafusion::prelude::{SessionConfig, SessionContext};
+use jni::objects::JClass;
+use jni::sys::jlong;
+use jni::JNIEnv;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::exception::catch_panic;
+use crate::handle;
+
+/// Spills to disk by the queries run for a SessionContext, accumulated over all queries.
+/// This is stored as an extension of the context's SessionConfig so it can be found
+/// from the TaskContext of a DataFrame.
+#[derive(Default)]
+pub struct SpillMetrics {
+    spill_count: AtomicUsize,
+    spilled_bytes: AtomicUsize,
+}
+
+impl SpillMetrics {
+    /// Add the spills of an executed plan and all of its children
+    fn record(&self, plan: &dyn ExecutionPlan) {
+        if let Some(metrics) = plan.metrics() {
+            self.spill_count
+                .fetch_add(metrics.spill_count().unwrap_or(0), Ordering::Relaxed);
+            self.spilled_bytes
+                .fetch_add(metrics.spilled_bytes().unwrap_or(0), Ordering::Relaxed);
+        }
+        for child in plan.children() {
+            self.record(child.as_ref());
+        }
+    }
+}
+
+/// Add spill metrics to the configuration of a new SessionContext
+pub fn with_spill_metrics(config: SessionConfig) -> SessionConfig {
+    config.with_extension(Arc::new(SpillMetrics::default()))
+}
+
+/// A physical plan being executed for a DataFrame.
+/// Its spill metrics are added to the metrics of its context when it is dropped,
+/// once its results have been read or written, or it has been cancelled.
+pub struct ExecutedPlan {
+    plan: Arc<dyn ExecutionPlan>,
+    spill_metrics: Option<Arc<SpillMetrics>>,
+}
+
+impl ExecutedPlan {
+    /// Get the physical plan
+    pub fn plan(&self) -> Arc<dyn ExecutionPlan> {
+        self.plan.clone()
+    }
+}
+
+impl Drop for ExecutedPlan {
+    fn drop(&mut self) {
+        if let Some(spill_metrics) = &self.spill_metrics {
+            spill_metrics.record(self.plan.as_ref());
+        }
+    }
+}
+
+/// Create the physical plan of a DataFrame, returning it with the session state to execute it
+/// with. The spill metrics of the plan are recorded once it is dropped, so it must be kept
+/// until the plan has finished executing.
+pub async fn plan_recording_spills(dataframe: DataFrame) -> Result<(SessionState, ExecutedPlan)> {
+    let (state, logical_plan) = dataframe.into_parts();
+    let spill_metrics = state.config().get_extension::<SpillMetrics>();
+    let plan = state.create_physical_plan(&logical_plan).await?;
+    Ok((
+        state,
+        ExecutedPlan {
+            plan,
+            spill_metrics,
+        },
+    ))
+}
+
+/// Execute a DataFrame, keeping its physical plan so that the spill metrics
+/// of the plan can be recorded once the stream has been read
+pub async fn execute_recording_spills(
+    dataframe: DataFrame,
+) -> Result<(SendableRecordBatchStream, ExecutedPlan)> {
+    let (state, plan) = plan_recording_spills(dataframe).await?;
+    let stream = execute_stream(plan.plan(), Arc::new(TaskContext::from(&state)))?;
+    Ok((stream, plan))
+}
+
+fn context_spill_metrics(pointer: jlong) -> Option<Arc<SpillMetrics>> {
+    let context = handle::get::<SessionContext>(pointer);
+    let config = context.copied_config();
+    config.get_extension::<SpillMetrics>()
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_getSpillCount(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        context_spill_metrics(pointer)
+            .map(|metrics| metrics.spill_count.load(Ordering::Relaxed) as jlong)
+            .unwrap_or(0)
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultSessionContext_getSpilledBytes(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        context_spill_metrics(pointer)
+            .map(|metrics| metrics.spilled_bytes.load(Ordering::Relaxed) as jlong)
+            .unwrap_or(0)
+    })
+}
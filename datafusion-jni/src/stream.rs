@@ -1,6 +1,9 @@
 use arrow::array::Array;
 use arrow::array::StructArray;
+use arrow::datatypes::SchemaRef;
 use arrow::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow::record_batch::RecordBatch;
+use datafusion::error::Result;
 use datafusion::physical_plan::SendableRecordBatchStream;
 use futures::stream::TryStreamExt;
 use jni::objects::{JClass, JObject};
@@ -8,10 +11,65 @@ use jni::sys::jlong;
 use jni::JNIEnv;
 use std::convert::Into;
 use std::ptr::addr_of_mut;
-use tokio::runtime::Runtime;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 
+use crate::async_callback::{cancelled_error, spawn_with_callback};
+use crate::exception::catch_panic;
+use crate::handle;
+use crate::runtime::TokioRuntime;
+use crate::spill_metrics::ExecutedPlan;
 use crate::util::{set_callback_result_error, set_callback_result_ok};
 
+/// A record batch stream read by a Java DefaultRecordBatchStream.
+/// Batches are read on the Tokio runtime, so the stream is guarded by an async mutex,
+/// and the schema is kept separately so it can be read while a batch is being loaded.
+/// Cancelling the stream stops any batch being loaded and drops the underlying stream.
+pub struct SharedRecordBatchStream {
+    schema: SchemaRef,
+    stream: Mutex<Option<SendableRecordBatchStream>>,
+    cancellation: CancellationToken,
+    /// Records spills once the stream is destroyed
+    _plan: ExecutedPlan,
+}
+
+impl SharedRecordBatchStream {
+    pub fn new(stream: SendableRecordBatchStream, plan: ExecutedPlan) -> Self {
+        Self {
+            schema: stream.schema(),
+            stream: Mutex::new(Some(stream)),
+            cancellation: CancellationToken::new(),
+            _plan: plan,
+        }
+    }
+
+    /// Get the next record batch, or None at the end of the stream
+    async fn next(&self) -> Result<Option<RecordBatch>> {
+        let mut stream = self.stream.lock().await;
+        let result = match stream.as_mut() {
+            Some(batches) => tokio::select! {
+                biased;
+                _ = self.cancellation.cancelled() => Err(cancelled_error("Record batch stream")),
+                batch = batches.try_next() => batch,
+            },
+            None => Err(cancelled_error("Record batch stream")),
+        };
+        if self.cancellation.is_cancelled() {
+            *stream = None;
+        }
+        result
+    }
+
+    /// Cancel the stream. If a batch is being loaded, the stream is dropped once that stops,
+    /// otherwise it is dropped immediately.
+    fn cancel(&self) {
+        self.cancellation.cancel();
+        if let Ok(mut stream) = self.stream.try_lock() {
+            *stream = None;
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultRecordBatchStream_next(
     mut env: JNIEnv,
@@ -19,27 +77,32 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultRecordBatchStream
     runtime: jlong,
     stream: jlong,
     callback: JObject,
-) {
-    let runtime = unsafe { &*(runtime as *const Runtime) };
-    let stream = unsafe { &mut *(stream as *mut SendableRecordBatchStream) };
-    runtime.block_on(async {
-        let next = stream.try_next().await;
-        match next {
-            Ok(Some(batch)) => {
-                // Convert to struct array for compatibility with FFI
-                let struct_array: StructArray = batch.into();
-                let array_data = struct_array.into_data();
-                let mut ffi_array = FFI_ArrowArray::new(&array_data);
-                set_callback_result_ok(&mut env, callback, addr_of_mut!(ffi_array));
-            }
-            Ok(None) => {
-                set_callback_result_ok(&mut env, callback, 0 as *mut FFI_ArrowSchema);
-            }
-            Err(err) => {
-                set_callback_result_error(&mut env, callback, &err);
-            }
-        }
-    });
+) -> jlong {
+    catch_panic(env.get_raw(), || {
+        let runtime = handle::get::<TokioRuntime>(runtime);
+        let stream = handle::get::<SharedRecordBatchStream>(stream);
+        spawn_with_callback(
+            &mut env,
+            &runtime,
+            callback,
+            async move { stream.next().await },
+            |env, callback, next| match next {
+                Ok(Some(batch)) => {
+                    // Convert to struct array for compatibility with FFI
+                    let struct_array: StructArray = batch.into();
+                    let array_data = struct_array.into_data();
+                    let mut ffi_array = FFI_ArrowArray::new(&array_data);
+                    set_callback_result_ok(env, callback, addr_of_mut!(ffi_array) as jlong);
+                }
+                Ok(None) => {
+                    set_callback_result_ok(env, callback, 0);
+                }
+                Err(err) => {
+                    set_callback_result_error(env, callback, &err);
+                }
+            },
+        )
+    })
 }
 
 #[no_mangle]
@@ -49,24 +112,38 @@ pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultRecordBatchStream
     stream: jlong,
     callback: JObject,
 ) {
-    let stream = unsafe { &*(stream as *const SendableRecordBatchStream) };
-    let schema = stream.schema();
-    let ffi_schema = FFI_ArrowSchema::try_from(&*schema);
-    match ffi_schema {
-        Ok(mut ffi_schema) => {
-            set_callback_result_ok(&mut env, callback, addr_of_mut!(ffi_schema));
-        }
-        Err(err) => {
-            set_callback_result_error(&mut env, callback, &err);
+    catch_panic(env.get_raw(), || {
+        let stream = handle::get::<SharedRecordBatchStream>(stream);
+        let ffi_schema = FFI_ArrowSchema::try_from(stream.schema.as_ref());
+        match ffi_schema {
+            Ok(mut ffi_schema) => {
+                set_callback_result_ok(&mut env, &callback, addr_of_mut!(ffi_schema) as jlong);
+            }
+            Err(err) => {
+                set_callback_result_error(&mut env, &callback, &err);
+            }
         }
-    }
+    })
 }
 
 #[no_mangle]
 pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultRecordBatchStream_destroy(
-    _env: JNIEnv,
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<SharedRecordBatchStream>(pointer).cancel();
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_DefaultRecordBatchStream_cancel(
+    env: JNIEnv,
     _class: JClass,
     pointer: jlong,
 ) {
-    let _ = unsafe { Box::from_raw(pointer as *mut SendableRecordBatchStream) };
+    catch_panic(env.get_raw(), || {
+        handle::get::<SharedRecordBatchStream>(pointer).cancel();
+    })
 }
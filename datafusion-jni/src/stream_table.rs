@@ -0,0 +1,101 @@
+use arrow::datatypes::SchemaRef;
+use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow::record_batch::{RecordBatch, RecordBatchReader};
+use datafusion::datasource::streaming::{PartitionStream, StreamingTable};
+use datafusion::datasource::TableProvider;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::context::TaskContext;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::SendableRecordBatchStream;
+use jni::objects::{JClass, JObject};
+use jni::sys::jlong;
+use jni::JNIEnv;
+use std::sync::{Arc, Mutex};
+
+use crate::exception::catch_panic;
+use crate::handle;
+use crate::util::set_object_result;
+
+/// Reader for an Arrow array stream exported from Java
+pub struct JavaStreamReader(pub ArrowArrayStreamReader);
+
+// The exported Java stream attaches the calling thread to the JVM when reading,
+// so it can be read from any thread
+unsafe impl Send for JavaStreamReader {}
+
+impl Iterator for JavaStreamReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(|batch| batch.map_err(DataFusionError::from))
+    }
+}
+
+/// A partition that reads record batches from an Arrow array stream.
+/// The stream can only be read once, so executing the partition again returns an error.
+struct ArrayStreamPartition {
+    schema: SchemaRef,
+    reader: Mutex<Option<JavaStreamReader>>,
+}
+
+impl PartitionStream for ArrayStreamPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let reader = handle::lock(&self.reader).take();
+        let batches: Box<dyn Iterator<Item = Result<RecordBatch>> + Send> = match reader {
+            Some(reader) => Box::new(reader),
+            None => Box::new(std::iter::once(Err(DataFusionError::Execution(
+                "Stream table has already been read".to_string(),
+            )))),
+        };
+        Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            futures::stream::iter(batches),
+        ))
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_StreamTable_create(
+    mut env: JNIEnv,
+    _class: JClass,
+    stream: jlong,
+    object_result: JObject,
+) {
+    catch_panic(env.get_raw(), || {
+        let reader =
+            unsafe { ArrowArrayStreamReader::from_raw(stream as *mut FFI_ArrowArrayStream) };
+        let table_provider_result = reader
+            .map_err(DataFusionError::from)
+            .and_then(|reader| {
+                let schema = reader.schema();
+                let partition: Arc<dyn PartitionStream> = Arc::new(ArrayStreamPartition {
+                    schema: schema.clone(),
+                    reader: Mutex::new(Some(JavaStreamReader(reader))),
+                });
+                StreamingTable::try_new(schema, vec![partition])
+            })
+            .map(|streaming_table| {
+                // Return as an Arc<dyn TableProvider> so this can be passed into SessionContext.registerTable
+                let table_provider: Arc<dyn TableProvider> = Arc::new(streaming_table);
+                handle::insert(table_provider)
+            });
+        set_object_result(&mut env, object_result, table_provider_result);
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_apache_arrow_datafusion_StreamTable_destroy(
+    env: JNIEnv,
+    _class: JClass,
+    pointer: jlong,
+) {
+    catch_panic(env.get_raw(), || {
+        handle::remove::<Arc<dyn TableProvider>>(pointer);
+    })
+}
@@ -0,0 +1,197 @@
+use arrow::array::{Array, ArrayRef};
+use arrow::datatypes::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{
+    Accumulator, AccumulatorFunctionImplementation, AggregateUDF, ReturnTypeFunction, Signature,
+    StateTypeFunction,
+};
+use datafusion::scalar::ScalarValue;
+use jni::objects::{JObject, JValue};
+use jni::sys::{jint, jlong};
+use jni::JNIEnv;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::ffi_arrays::FfiArrays;
+use crate::java_callbacks::JavaCallbacks;
+use crate::udf::volatility_from_id;
+use crate::util::{import_data_types, java_call_error};
+
+/// Create an aggregate UDF that is implemented by a Java AggregateUdfCallbacks object,
+/// with argument, return and state types exported from Java as schema fields
+pub fn create_java_udaf(
+    env: &mut JNIEnv,
+    name: &str,
+    argument_types: jlong,
+    return_type: jlong,
+    state_types: jlong,
+    volatility: jint,
+    callbacks: JObject,
+) -> Result<AggregateUDF> {
+    let argument_types = import_data_types(argument_types)?;
+    let return_type = Arc::new(
+        import_data_types(return_type)?
+            .pop()
+            .ok_or_else(|| DataFusionError::Internal("Missing UDAF return type".to_string()))?,
+    );
+    let state_types = Arc::new(import_data_types(state_types)?);
+    let volatility = volatility_from_id(volatility)?;
+    let callbacks = Arc::new(JavaCallbacks::new(env, callbacks)?);
+
+    let signature = Signature::exact(argument_types, volatility);
+    let accumulator_return_type = return_type.clone();
+    let return_type_function: ReturnTypeFunction = Arc::new(move |_| Ok(return_type.clone()));
+    let accumulator_state_types = state_types.clone();
+    let accumulator: AccumulatorFunctionImplementation = Arc::new(move |_| {
+        let accumulator = JavaAccumulator::new(
+            &callbacks,
+            accumulator_return_type.clone(),
+            accumulator_state_types.clone(),
+        )?;
+        Ok(Box::new(accumulator) as Box<dyn Accumulator>)
+    });
+    let state_type_function: StateTypeFunction = Arc::new(move |_| Ok(state_types.clone()));
+    Ok(AggregateUDF::new(
+        name,
+        &signature,
+        &return_type_function,
+        &accumulator,
+        &state_type_function,
+    ))
+}
+
+/// An Accumulator that calls back into a Java AccumulatorCallbacks object.
+/// DataFusion creates an accumulator for each group of a grouped aggregation, and each one
+/// holds a JNI global reference to its Java accumulator until it is dropped, so a GROUP BY
+/// with very many groups can exhaust the JVM's global reference table.
+struct JavaAccumulator {
+    callbacks: JavaCallbacks,
+    return_type: Arc<DataType>,
+    state_types: Arc<Vec<DataType>>,
+}
+
+impl JavaAccumulator {
+    fn new(
+        udaf_callbacks: &JavaCallbacks,
+        return_type: Arc<DataType>,
+        state_types: Arc<Vec<DataType>>,
+    ) -> Result<Self> {
+        let callbacks = udaf_callbacks.call(|env, object| {
+            let accumulator = env
+                .call_method(
+                    object,
+                    "createAccumulator",
+                    "()Lorg/apache/arrow/datafusion/AccumulatorCallbacks;",
+                    &[],
+                )
+                .and_then(|accumulator| accumulator.l())
+                .map_err(|err| java_call_error(env, err))?;
+            JavaCallbacks::new(env, accumulator)
+        })?;
+        Ok(Self {
+            callbacks,
+            return_type,
+            state_types,
+        })
+    }
+
+    /// Pass arrays to a Java method that accepts array and schema addresses
+    fn call_with_arrays(&self, method: &str, arrays: &[ArrayRef]) -> Result<()> {
+        let mut ffi_arrays = FfiArrays::export(arrays)?;
+        self.callbacks.call(|env, object| {
+            let (java_arrays, java_schemas) = ffi_arrays.java_addresses(env)?;
+            env.call_method(
+                object,
+                method,
+                "([J[J)V",
+                &[JValue::Object(&java_arrays), JValue::Object(&java_schemas)],
+            )
+            .map_err(|err| java_call_error(env, err))?;
+            Ok(())
+        })
+    }
+
+    /// Get arrays from a Java method that exports into array and schema addresses
+    fn call_for_arrays(&self, method: &str, count: usize) -> Result<Vec<ArrayRef>> {
+        let mut ffi_arrays = FfiArrays::empty(count);
+        self.callbacks.call(|env, object| {
+            let (java_arrays, java_schemas) = ffi_arrays.java_addresses(env)?;
+            env.call_method(
+                object,
+                method,
+                "([J[J)V",
+                &[JValue::Object(&java_arrays), JValue::Object(&java_schemas)],
+            )
+            .map_err(|err| java_call_error(env, err))?;
+            Ok(())
+        })?;
+        ffi_arrays.import()
+    }
+}
+
+impl Debug for JavaAccumulator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JavaAccumulator")
+            .field("return_type", &self.return_type)
+            .field("state_types", &self.state_types)
+            .finish()
+    }
+}
+
+impl Accumulator for JavaAccumulator {
+    fn state(&self) -> Result<Vec<ScalarValue>> {
+        self.call_for_arrays("state", self.state_types.len())?
+            .iter()
+            .zip(self.state_types.iter())
+            .map(|(array, state_type)| single_value("state", array, state_type))
+            .collect()
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.call_with_arrays("updateBatch", values)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.call_with_arrays("mergeBatch", states)
+    }
+
+    fn evaluate(&self) -> Result<ScalarValue> {
+        let result = self.call_for_arrays("evaluate", 1)?;
+        single_value("evaluate", &result[0], &self.return_type)
+    }
+
+    fn size(&self) -> usize {
+        // Memory used by the Java accumulator is not tracked
+        std::mem::size_of_val(self)
+    }
+}
+
+/// Get the single value of an array returned by a Java accumulator method,
+/// checking that it has the expected type
+fn single_value(method: &str, array: &ArrayRef, data_type: &DataType) -> Result<ScalarValue> {
+    if array.data_type() != data_type {
+        return Err(DataFusionError::Execution(format!(
+            "Java accumulator {method} returned an array of type {} but {} was expected",
+            array.data_type(),
+            data_type
+        )));
+    }
+    if array.len() != 1 {
+        return Err(DataFusionError::Execution(format!(
+            "Java accumulator {method} returned an array of length {} but 1 was expected",
+            array.len()
+        )));
+    }
+    ScalarValue::try_from_array(array, 0)
+}
+
+// Close the Java accumulator so it can release any resources it holds
+impl Drop for JavaAccumulator {
+    fn drop(&mut self) {
+        let _ = self.callbacks.call(|env, object| {
+            env.call_method(object, "close", "()V", &[])
+                .map(|_| ())
+                .map_err(|err| java_call_error(env, err))
+        });
+    }
+}
@@ -0,0 +1,100 @@
+use arrow::array::{Array, ArrayRef};
+use arrow::datatypes::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{create_udf, ScalarUDF, Volatility};
+use datafusion::physical_plan::functions::make_scalar_function;
+use jni::objects::{JObject, JValue};
+use jni::sys::{jint, jlong};
+use jni::JNIEnv;
+use std::sync::Arc;
+
+use crate::ffi_arrays::FfiArrays;
+use crate::java_callbacks::JavaCallbacks;
+use crate::util::{import_data_types, java_call_error};
+
+/// Convert the id of a Java Volatility enum value into a Volatility
+pub fn volatility_from_id(id: jint) -> Result<Volatility> {
+    match id {
+        0 => Ok(Volatility::Immutable),
+        1 => Ok(Volatility::Stable),
+        2 => Ok(Volatility::Volatile),
+        _ => Err(DataFusionError::NotImplemented(format!(
+            "Unsupported volatility id {id}"
+        ))),
+    }
+}
+
+/// Create a scalar UDF that is implemented by a Java ScalarUdfCallbacks object,
+/// with argument and return types exported from Java as schema fields
+pub fn create_java_udf(
+    env: &mut JNIEnv,
+    name: &str,
+    argument_types: jlong,
+    return_type: jlong,
+    volatility: jint,
+    callbacks: JObject,
+) -> Result<ScalarUDF> {
+    let input_types = import_data_types(argument_types)?;
+    let return_type = import_data_types(return_type)?
+        .pop()
+        .ok_or_else(|| DataFusionError::Internal("Missing UDF return type".to_string()))?;
+    let volatility = volatility_from_id(volatility)?;
+    let callbacks = JavaCallbacks::new(env, callbacks)?;
+    let udf_return_type = return_type.clone();
+    let implementation = make_scalar_function(move |args: &[ArrayRef]| {
+        call_java_function(&callbacks, args, &udf_return_type)
+    });
+    Ok(create_udf(
+        name,
+        input_types,
+        Arc::new(return_type),
+        volatility,
+        implementation,
+    ))
+}
+
+/// Export arrays to Java, call a Java function with them and import the resulting array
+pub fn call_java_function(
+    callbacks: &JavaCallbacks,
+    args: &[ArrayRef],
+    return_type: &DataType,
+) -> Result<ArrayRef> {
+    let mut ffi_args = FfiArrays::export(args)?;
+    let mut ffi_result = FfiArrays::empty(1);
+    callbacks.call(|env, object| {
+        let (java_arrays, java_schemas) = ffi_args.java_addresses(env)?;
+        let (result_array, result_schema) = ffi_result.address(0);
+        env.call_method(
+            object,
+            "evaluate",
+            "([J[JJJ)V",
+            &[
+                JValue::Object(&java_arrays),
+                JValue::Object(&java_schemas),
+                JValue::Long(result_array),
+                JValue::Long(result_schema),
+            ],
+        )
+        .map_err(|err| java_call_error(env, err))?;
+        Ok(())
+    })?;
+
+    let result = ffi_result.import()?.remove(0);
+    if result.data_type() != return_type {
+        return Err(DataFusionError::Execution(format!(
+            "Java function returned an array of type {} but {} was expected",
+            result.data_type(),
+            return_type
+        )));
+    }
+    if let Some(arg) = args.first() {
+        if result.len() != arg.len() {
+            return Err(DataFusionError::Execution(format!(
+                "Java function returned an array of length {} but {} was expected",
+                result.len(),
+                arg.len()
+            )));
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,92 @@
+use std::ffi::{c_char, CStr};
+
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::context::SessionContext;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
+use libloading::{Library, Symbol};
+
+/// Name of the function that UDF libraries must export to declare the UDF ABI they were built for
+pub const UDF_ABI_SYMBOL: &str = "datafusion_jni_udf_abi";
+
+/// The UDF ABI of this library, which UDF libraries must return from their ABI function.
+/// This changes whenever the DataFusion version or the registration function changes.
+pub const UDF_ABI: &str = "datafusion-jni-udf-abi/1 datafusion/25.0.0";
+
+/// Signature of the ABI function exported by UDF libraries, which returns a pointer to
+/// a NUL terminated string with static lifetime. Unlike the registration function this has
+/// a stable C ABI, so it is safe to call before the library is known to be compatible.
+pub type UdfAbi = unsafe extern "C" fn() -> *const c_char;
+
+/// Name of the function that UDF libraries must export to register their functions
+pub const REGISTER_UDFS_SYMBOL: &str = "datafusion_jni_register_udfs";
+
+/// Signature of the registration function exported by UDF libraries.
+///
+/// The function adds the library's scalar and aggregate UDFs to the vectors it is given.
+/// Rust does not have a stable ABI, so libraries must be built with the same Rust compiler,
+/// DataFusion version and DataFusion features as this library. Only the DataFusion version
+/// is checked when a library is loaded, so using a different compiler is undefined behaviour.
+/// For example:
+///
+/// ```ignore
+/// #[no_mangle]
+/// pub extern "C" fn datafusion_jni_udf_abi() -> *const c_char {
+///     "datafusion-jni-udf-abi/1 datafusion/25.0.0\0".as_ptr().cast()
+/// }
+///
+/// #[no_mangle]
+/// pub fn datafusion_jni_register_udfs(
+///     scalar_udfs: &mut Vec<ScalarUDF>,
+///     aggregate_udfs: &mut Vec<AggregateUDF>,
+/// ) {
+///     scalar_udfs.push(create_udf(...));
+/// }
+/// ```
+pub type RegisterUdfs = fn(&mut Vec<ScalarUDF>, &mut Vec<AggregateUDF>);
+
+/// Load a shared library and register the UDFs it provides with a context
+pub fn load_udf_library(context: &SessionContext, path: &str) -> Result<()> {
+    let library = unsafe { Library::new(path) }.map_err(library_error)?;
+    let mut scalar_udfs = Vec::new();
+    let mut aggregate_udfs = Vec::new();
+    {
+        check_udf_abi(&library, path)?;
+        let register_udfs: Symbol<RegisterUdfs> =
+            unsafe { library.get(REGISTER_UDFS_SYMBOL.as_bytes()) }.map_err(library_error)?;
+        register_udfs(&mut scalar_udfs, &mut aggregate_udfs);
+    }
+    // Registered functions point into the library's code and may be used by any context
+    // or plan that outlives this one, so the library is never unloaded
+    std::mem::forget(library);
+    for udf in scalar_udfs {
+        context.register_udf(udf);
+    }
+    for udaf in aggregate_udfs {
+        context.register_udaf(udaf);
+    }
+    Ok(())
+}
+
+/// Check that a UDF library was built for the same UDF ABI as this library
+fn check_udf_abi(library: &Library, path: &str) -> Result<()> {
+    let udf_abi: Symbol<UdfAbi> =
+        unsafe { library.get(UDF_ABI_SYMBOL.as_bytes()) }.map_err(library_error)?;
+    let abi = unsafe { udf_abi() };
+    if abi.is_null() {
+        return Err(DataFusionError::Plan(format!(
+            "UDF library {path} returned a null UDF ABI"
+        )));
+    }
+    let abi = unsafe { CStr::from_ptr(abi) };
+    if abi.to_bytes() != UDF_ABI.as_bytes() {
+        return Err(DataFusionError::Plan(format!(
+            "UDF library {path} was built for UDF ABI {} but {UDF_ABI} is required",
+            abi.to_string_lossy()
+        )));
+    }
+    Ok(())
+}
+
+fn library_error(error: libloading::Error) -> DataFusionError {
+    DataFusionError::External(Box::new(error))
+}
@@ -1,102 +1,184 @@
 use std::error::Error;
 
-use jni::objects::JObject;
+use arrow::array::StructArray;
+use arrow::datatypes::{DataType, Schema};
+use arrow::error::ArrowError;
+use arrow::ffi::{ArrowArray, ArrowArrayRef, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow::record_batch::RecordBatch;
+use datafusion::error::DataFusionError;
+use jni::objects::{JObject, JObjectArray, JString};
 use jni::sys::jlong;
 use jni::JNIEnv;
 
-/// Set error result using a Consumer<String> Java callback
-pub fn call_error_handler<Err: Error>(
+use crate::exception::create_exception;
+
+/// Signature of the ObjectResultCallback callback method
+const OBJECT_RESULT_CALLBACK_SIGNATURE: &str =
+    "(Lorg/apache/arrow/datafusion/DataFusionException;J)V";
+
+/// Set error result using a Consumer<DataFusionException> Java callback,
+/// which is called with null if the result is Ok
+pub fn call_error_handler<Err: Error + 'static>(
     env: &mut JNIEnv,
-    callback: JObject,
+    callback: &JObject,
     result: Result<(), Err>,
 ) {
-    match result {
-        Ok(_) => {
-            let err_message = JObject::null();
-            env.call_method(
-                callback,
-                "accept",
-                "(Ljava/lang/Object;)V",
-                &[(&err_message).into()],
-            )
-            .expect("Failed to call error handler with null");
-        }
-        Err(err) => {
-            let err_message = env
-                .new_string(err.to_string())
-                .expect("Couldn't create java string for error message");
-            env.call_method(
-                callback,
-                "accept",
-                "(Ljava/lang/Object;)V",
-                &[(&err_message).into()],
-            )
-            .expect("Failed to call error handler with null");
-        }
-    };
+    let exception = result_exception(env, result);
+    env.call_method(
+        callback,
+        "accept",
+        "(Ljava/lang/Object;)V",
+        &[(&exception).into()],
+    )
+    .expect("Failed to call error handler");
 }
 
-/// Set result by calling an ObjectResultCallback
-pub fn set_callback_result<T, Err: Error>(
+/// Set result by calling an ObjectResultCallback with an object handle or FFI struct address
+pub fn set_callback_result<Err: Error + 'static>(
     env: &mut JNIEnv,
-    callback: JObject,
-    address: Result<*mut T, Err>,
+    callback: &JObject,
+    value: Result<jlong, Err>,
 ) {
-    match address {
-        Ok(address) => set_callback_result_ok(env, callback, address),
+    match value {
+        Ok(value) => set_callback_result_ok(env, callback, value),
         Err(err) => set_callback_result_error(env, callback, &err),
     };
 }
 
 /// Set success result by calling an ObjectResultCallback
-pub fn set_callback_result_ok<T>(env: &mut JNIEnv, callback: JObject, address: *mut T) {
-    let err_message = JObject::null();
+pub fn set_callback_result_ok(env: &mut JNIEnv, callback: &JObject, value: jlong) {
+    let exception = JObject::null();
     env.call_method(
         callback,
         "callback",
-        "(Ljava/lang/String;J)V",
-        &[(&err_message).into(), (address as jlong).into()],
+        OBJECT_RESULT_CALLBACK_SIGNATURE,
+        &[(&exception).into(), value.into()],
     )
-    .expect("Failed to call object result callback with address");
+    .expect("Failed to call object result callback with value");
 }
 
 /// Set error result by calling an ObjectResultCallback
-pub fn set_callback_result_error<T: Error>(env: &mut JNIEnv, callback: JObject, error: &T) {
-    let err_message = env
-        .new_string(error.to_string())
-        .expect("Couldn't create java string for error message");
+pub fn set_callback_result_error<T: Error + 'static>(
+    env: &mut JNIEnv,
+    callback: &JObject,
+    error: &T,
+) {
+    let exception = create_exception(env, error).expect("Couldn't create java exception");
     let address = -1 as jlong;
     env.call_method(
         callback,
         "callback",
-        "(Ljava/lang/String;J)V",
-        &[(&err_message).into(), address.into()],
+        OBJECT_RESULT_CALLBACK_SIGNATURE,
+        &[(&exception).into(), address.into()],
     )
     .expect("Failed to call object result callback with error");
 }
 
-/// Set result on an ObjectResult instance
-pub fn set_object_result<T, Err: Error>(
+/// Set result on an ObjectResult instance with the handle of a new object
+pub fn set_object_result<Err: Error + 'static>(
     env: &mut JNIEnv,
     result: JObject,
-    address: Result<*mut T, Err>,
+    handle: Result<jlong, Err>,
 ) {
-    match address {
-        Ok(address) => {
-            env.call_method(result, "setOk", "(J)V", &[(address as jlong).into()])
+    match handle {
+        Ok(handle) => {
+            env.call_method(result, "setOk", "(J)V", &[handle.into()])
                 .expect("Failed to call object result setOk");
         }
         Err(err) => {
-            let err_message = env
-                .new_string(err.to_string())
-                .expect("Couldn't create java string for error message");
+            let exception = create_exception(env, &err).expect("Couldn't create java exception");
             env.call_method(
                 result,
                 "setError",
-                "(Ljava/lang/String;)V",
-                &[(&err_message).into()],
+                "(Lorg/apache/arrow/datafusion/DataFusionException;)V",
+                &[(&exception).into()],
             )
             .expect("Failed to call object result setError");
         }
     }
 }
+
+/// Get a Java DataFusionException for the error in a result, or null if the result is Ok
+pub fn result_exception<'local, T, Err: Error + 'static>(
+    env: &mut JNIEnv<'local>,
+    result: Result<T, Err>,
+) -> JObject<'local> {
+    match result {
+        Ok(_) => JObject::null(),
+        Err(err) => create_exception(env, &err)
+            .expect("Couldn't create java exception")
+            .into(),
+    }
+}
+
+/// Import a schema that has been exported from Java using the Arrow C data interface.
+/// The FFI schema remains owned by Java, so the imported schema is a copy.
+pub fn import_schema(address: jlong) -> Result<Schema, ArrowError> {
+    let ffi_schema = unsafe { &*(address as *const FFI_ArrowSchema) };
+    Schema::try_from(ffi_schema)
+}
+
+/// Import a list of data types that were exported from Java as the fields of a schema
+pub fn import_data_types(address: jlong) -> Result<Vec<DataType>, ArrowError> {
+    let schema = import_schema(address)?;
+    Ok(schema
+        .fields()
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect())
+}
+
+/// Import a record batch that was exported from Java as a struct array using the C data interface.
+/// Ownership of the array data moves to Rust, leaving an empty released array at the array address,
+/// while the schema is only borrowed, so the array is imported with a copy of it.
+pub fn import_record_batch(array: jlong, schema: jlong) -> Result<RecordBatch, ArrowError> {
+    let ffi_array =
+        unsafe { std::ptr::replace(array as *mut FFI_ArrowArray, FFI_ArrowArray::empty()) };
+    let data_type = DataType::try_from(unsafe { &*(schema as *const FFI_ArrowSchema) })?;
+    let ffi_schema = FFI_ArrowSchema::try_from(&data_type)?;
+    let array_data = ArrowArray::new(ffi_array, ffi_schema).to_data()?;
+    Ok(RecordBatch::from(StructArray::from(array_data)))
+}
+
+/// Convert an error from calling into Java into a DataFusionError.
+/// If a Java exception was thrown it is cleared and its description is used as the error message.
+pub fn java_call_error(env: &mut JNIEnv, error: jni::errors::Error) -> DataFusionError {
+    if let jni::errors::Error::JavaException = error {
+        if let Ok(throwable) = env.exception_occurred() {
+            let _ = env.exception_clear();
+            let message = env
+                .call_method(&throwable, "toString", "()Ljava/lang/String;", &[])
+                .and_then(|message| message.l())
+                .and_then(|message| env.get_string(&JString::from(message)).map(String::from));
+            if let Ok(message) = message {
+                return DataFusionError::Execution(format!("Java exception: {message}"));
+            }
+        }
+    }
+    jni_error(error)
+}
+
+/// Convert a JNI error into a DataFusionError
+pub fn jni_error(error: jni::errors::Error) -> DataFusionError {
+    DataFusionError::External(Box::new(error))
+}
+
+/// Get the values of a Java String array
+pub fn string_array(env: &mut JNIEnv, array: &JObjectArray) -> Vec<String> {
+    let length = env
+        .get_array_length(array)
+        .expect("Couldn't get array length");
+    let mut values: Vec<String> = Vec::new();
+    for i in 0..length {
+        let value: JString = env
+            .get_object_array_element(array, i)
+            .expect("Couldn't get array string element")
+            .into();
+        let value: String = env
+            .get_string(&value)
+            .expect("Couldn't get array element as string!")
+            .into();
+        values.push(value);
+    }
+    values
+}
@@ -0,0 +1,38 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use datafusion::arrow::array::{ArrayRef, Int64Array};
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::cast::as_int64_array;
+use datafusion::logical_expr::{create_udf, AggregateUDF, ScalarUDF, Volatility};
+use datafusion::physical_plan::functions::make_scalar_function;
+
+/// The UDF ABI this library was built for, which must match the ABI of datafusion_jni
+const UDF_ABI: &str = "datafusion-jni-udf-abi/1 datafusion/25.0.0\0";
+
+#[no_mangle]
+pub extern "C" fn datafusion_jni_udf_abi() -> *const c_char {
+    UDF_ABI.as_ptr().cast()
+}
+
+#[no_mangle]
+pub fn datafusion_jni_register_udfs(
+    scalar_udfs: &mut Vec<ScalarUDF>,
+    _aggregate_udfs: &mut Vec<AggregateUDF>,
+) {
+    let add_one = make_scalar_function(|args: &[ArrayRef]| {
+        let values = as_int64_array(&args[0])?;
+        let result: Int64Array = values
+            .iter()
+            .map(|value| value.map(|value| value + 1))
+            .collect();
+        Ok(Arc::new(result) as ArrayRef)
+    });
+    scalar_udfs.push(create_udf(
+        "add_one",
+        vec![DataType::Int64],
+        Arc::new(DataType::Int64),
+        Volatility::Immutable,
+        add_one,
+    ));
+}